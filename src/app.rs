@@ -1,7 +1,9 @@
 use super::*;
 
+const CALLBACK_BASE_ID: i32 = 1000;
+
 /// An id for a steam app/game
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AppId(pub u32);
 impl From<u32> for AppId {
@@ -9,11 +11,32 @@ impl From<u32> for AppId {
         AppId(id)
     }
 }
+impl From<AppId> for u32 {
+    fn from(id: AppId) -> Self {
+        id.0
+    }
+}
+impl fmt::Display for AppId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// An id for a depot of a steam app
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DepotId(pub u32);
+impl From<u32> for DepotId {
+    fn from(id: u32) -> Self {
+        DepotId(id)
+    }
+}
 
 /// Access to the steam apps interface
 pub struct Apps<Manager> {
     pub(crate) apps: *mut sys::ISteamApps,
-    pub(crate) _inner: Arc<Inner<Manager>>,
+    pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
 }
 
 impl<Manager> Apps<Manager> {
@@ -31,6 +54,82 @@ impl<Manager> Apps<Manager> {
         unsafe { sys::SteamAPI_ISteamApps_BIsDlcInstalled(self.apps, app_id.0) }
     }
 
+    /// Returns the number of DLCs for the current app.
+    ///
+    /// Used together with `dlc_data_by_index` to enumerate its DLCs.
+    pub fn dlc_count(&self) -> u32 {
+        unsafe { sys::SteamAPI_ISteamApps_GetDLCCount(self.apps).max(0) as u32 }
+    }
+
+    /// Returns information about the DLC at the given index.
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn dlc_data_by_index(&self, index: u32) -> Option<DlcData> {
+        unsafe {
+            let mut app_id = 0;
+            let mut available = false;
+            let mut name = vec![0; 128];
+            if sys::SteamAPI_ISteamApps_BGetDLCDataByIndex(
+                self.apps,
+                index as _,
+                &mut app_id,
+                &mut available,
+                name.as_mut_ptr(),
+                name.len() as _,
+            ) {
+                let name = CStr::from_ptr(name.as_ptr());
+                Some(DlcData {
+                    app_id: AppId(app_id),
+                    available,
+                    name: name.to_string_lossy().into_owned(),
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns a list of all DLCs for the current app.
+    pub fn dlcs(&self) -> Vec<DlcData> {
+        (0..self.dlc_count())
+            .filter_map(|i| self.dlc_data_by_index(i))
+            .collect()
+    }
+
+    /// Installs an optional DLC.
+    ///
+    /// A `DlcInstalled` callback will be fired once the DLC has been
+    /// installed.
+    pub fn install_dlc(&self, app_id: AppId) {
+        unsafe { sys::SteamAPI_ISteamApps_InstallDLC(self.apps, app_id.0) }
+    }
+
+    /// Uninstalls an optional DLC.
+    pub fn uninstall_dlc(&self, app_id: AppId) {
+        unsafe { sys::SteamAPI_ISteamApps_UninstallDLC(self.apps, app_id.0) }
+    }
+
+    /// Returns the download progress of an optional DLC as
+    /// `(bytes downloaded, bytes total)`.
+    ///
+    /// Returns `None` if the DLC isn't currently downloading.
+    pub fn dlc_download_progress(&self, app_id: AppId) -> Option<(u64, u64)> {
+        unsafe {
+            let mut downloaded = 0;
+            let mut total = 0;
+            if sys::SteamAPI_ISteamApps_GetDlcDownloadProgress(
+                self.apps,
+                app_id.0,
+                &mut downloaded,
+                &mut total,
+            ) {
+                Some((downloaded, total))
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns whether the user is subscribed to the app with the given
     /// ID.
     ///
@@ -45,6 +144,37 @@ impl<Manager> Apps<Manager> {
         unsafe { sys::SteamAPI_ISteamApps_BIsSubscribedFromFreeWeekend(self.apps) }
     }
 
+    /// Returns whether the user is borrowing the current app via
+    /// Steam Family Sharing.
+    ///
+    /// The original owner can be found with `app_owner`.
+    pub fn is_subscribed_from_family_sharing(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamApps_BIsSubscribedFromFamilySharing(self.apps) }
+    }
+
+    /// Returns whether the current app is a timed trial.
+    ///
+    /// If it is, returns how long the trial lasts and how much of it
+    /// has already been played.
+    pub fn timed_trial(&self) -> Option<TimedTrial> {
+        unsafe {
+            let mut seconds_allowed = 0;
+            let mut seconds_played = 0;
+            if sys::SteamAPI_ISteamApps_BIsTimedTrial(
+                self.apps,
+                &mut seconds_allowed,
+                &mut seconds_played,
+            ) {
+                Some(TimedTrial {
+                    seconds_allowed,
+                    seconds_played,
+                })
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns whether the user has a VAC ban on their account.
     pub fn is_vac_banned(&self) -> bool {
         unsafe { sys::SteamAPI_ISteamApps_BIsVACBanned(self.apps) }
@@ -90,6 +220,22 @@ impl<Manager> Apps<Manager> {
         }
     }
 
+    /// Returns the depots of the app with the given ID that are currently
+    /// installed, in mount order.
+    pub fn installed_depots(&self, app_id: AppId) -> Vec<DepotId> {
+        unsafe {
+            let mut depots = vec![0; 256];
+            let count = sys::SteamAPI_ISteamApps_GetInstalledDepots(
+                self.apps,
+                app_id.0,
+                depots.as_mut_ptr(),
+                depots.len() as u32,
+            );
+            depots.truncate(count as usize);
+            depots.into_iter().map(DepotId).collect()
+        }
+    }
+
     /// Returns the steam id of the original owner of the app.
     ///
     /// Differs from the current user if the app is borrowed.
@@ -139,6 +285,118 @@ impl<Manager> Apps<Manager> {
         }
     }
 
+    /// Returns the value of the given launch parameter if the game was
+    /// run via a `steam://run/<appid>//?param1=value1&param2=value2` URL.
+    ///
+    /// Parameter names starting with `@` are reserved for internal use and
+    /// will always return `None`. Returns `None` if the parameter wasn't set.
+    ///
+    /// See [Steam API](https://partner.steamgames.com/doc/api/ISteamApps#GetLaunchQueryParam)
+    pub fn launch_query_param(&self, key: &str) -> Option<String> {
+        let key = CString::new(key).unwrap();
+        unsafe {
+            let value = sys::SteamAPI_ISteamApps_GetLaunchQueryParam(self.apps, key.as_ptr());
+            if value.is_null() {
+                return None;
+            }
+            let value = CStr::from_ptr(value);
+            if value.is_empty() {
+                None
+            } else {
+                Some(value.to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Signals Steam that the game files seem corrupt or missing, which
+    /// will make Steam verify the game files next time the game is launched.
+    ///
+    /// If `missing_files_only` is set only missing files will be
+    /// re-downloaded instead of verifying every file.
+    pub fn mark_content_corrupt(&self, missing_files_only: bool) -> bool {
+        unsafe { sys::SteamAPI_ISteamApps_MarkContentCorrupt(self.apps, missing_files_only) }
+    }
+
+    /// Asynchronously retrieves the size and SHA1 hash of a file in the
+    /// app's depots.
+    ///
+    /// `file_name` is relative to the app's install directory. This can be
+    /// used to verify that game files have not been modified.
+    pub fn file_details<F>(&self, file_name: &str, cb: F)
+    where
+        F: FnOnce(Result<FileDetails, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let file_name = CString::new(file_name).unwrap();
+            let api_call = sys::SteamAPI_ISteamApps_GetFileDetails(self.apps, file_name.as_ptr());
+            register_call_result::<sys::FileDetailsResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 23,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(FileDetails {
+                            size: v.m_ulFileSize,
+                            sha1: v.m_FileSHA,
+                            flags: v.m_unFlags,
+                        })
+                    })
+                },
+            );
+        }
+    }
+
+    /// Returns what the game was asked to do when it was launched, e.g.
+    /// because the user accepted a lobby invite or joined a friend's game.
+    ///
+    /// This checks the `+connect_lobby`/`connect_lobby` and
+    /// `+connect`/`connect` launch query parameters, the Steam URL command
+    /// line and finally the process arguments for `+connect_lobby <id>`
+    /// and `+connect <address>`.
+    ///
+    /// When the game is already running, invites are delivered through
+    /// the `GameLobbyJoinRequested` callback and `NewUrlLaunchParameters`
+    /// instead.
+    pub fn launch_intent(&self) -> LaunchIntent {
+        for key in ["+connect_lobby", "connect_lobby"] {
+            if let Some(value) = self.launch_query_param(key) {
+                if let Ok(lobby) = value.trim().parse() {
+                    return LaunchIntent::JoinLobby(LobbyId(lobby));
+                }
+                if let intent @ LaunchIntent::JoinLobby(_) = LaunchIntent::parse(&value) {
+                    return intent;
+                }
+            }
+        }
+        for key in ["+connect", "connect"] {
+            if let Some(value) = self.launch_query_param(key) {
+                // The value may be a whole `+connect <address>` string
+                match LaunchIntent::parse(&value) {
+                    LaunchIntent::None if !value.trim().is_empty() => {
+                        return LaunchIntent::ConnectServer(value.trim().to_owned());
+                    }
+                    LaunchIntent::None => {}
+                    intent => return intent,
+                }
+            }
+        }
+        match LaunchIntent::parse(&self.launch_command_line()) {
+            LaunchIntent::None => {
+                let args: Vec<String> = std::env::args_os()
+                    .skip(1)
+                    .map(|arg| arg.to_string_lossy().into_owned())
+                    .collect();
+                LaunchIntent::parse(&args.join(" "))
+            }
+            intent => intent,
+        }
+    }
+
     /// Returns the command line if the game was launched via Steam URL
     ///
     /// If the game was not launched through Steam URL, this returns an empty string.
@@ -156,4 +414,163 @@ impl<Manager> Apps<Manager> {
             command_line.to_string_lossy().into_owned()
         }
     }
+
+    async_call_results! {
+        fn file_details_async => file_details(file_name: &str) -> Result<FileDetails, SteamError>;
+    }
+}
+
+/// Information about a DLC of the current app
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DlcData {
+    pub app_id: AppId,
+    /// Whether the DLC is currently available on the store
+    pub available: bool,
+    pub name: String,
+}
+
+/// How the game was asked to start, as returned by `Apps::launch_intent`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LaunchIntent {
+    /// The user accepted an invite to the given lobby
+    JoinLobby(LobbyId),
+    /// The user wants to connect to the given server address
+    ConnectServer(String),
+    /// The game was launched normally
+    None,
+}
+
+impl LaunchIntent {
+    /// Parses a launch intent from a command line or a rich presence
+    /// `connect` string containing `+connect_lobby <id>` or
+    /// `+connect <address>`.
+    pub fn parse(command_line: &str) -> LaunchIntent {
+        let mut args = command_line.split_whitespace();
+        while let Some(arg) = args.next() {
+            match arg {
+                "+connect_lobby" => {
+                    if let Some(lobby) = args.next().and_then(|v| v.parse().ok()) {
+                        return LaunchIntent::JoinLobby(LobbyId(lobby));
+                    }
+                }
+                "+connect" => {
+                    if let Some(address) = args.next() {
+                        return LaunchIntent::ConnectServer(address.to_owned());
+                    }
+                }
+                _ => {}
+            }
+        }
+        LaunchIntent::None
+    }
+}
+
+/// Details of a file in the app's depots, as returned by
+/// `Apps::file_details`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FileDetails {
+    /// The size of the file in bytes
+    pub size: u64,
+    /// The SHA1 hash of the file
+    pub sha1: [u8; 20],
+    pub flags: u32,
+}
+
+/// The play time of a timed trial, as returned by `Apps::timed_trial`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimedTrial {
+    /// How many seconds the app can be played in total
+    pub seconds_allowed: u32,
+    /// How many seconds the app has already been played
+    pub seconds_played: u32,
+}
+
+/// Called after the user gains ownership of a DLC and it has been
+/// installed.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DlcInstalled {
+    /// The app id of the DLC that was installed
+    pub app_id: AppId,
+}
+
+unsafe impl Callback for DlcInstalled {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::DlcInstalled_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::DlcInstalled_t);
+        DlcInstalled {
+            app_id: AppId(val.m_nAppID),
+        }
+    }
+}
+
+/// Called when the game is already running and the user clicks a
+/// `steam://run/<appid>//<params>` URL.
+///
+/// The new parameters can be read with `Apps::launch_query_param` and
+/// `Apps::launch_command_line`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NewUrlLaunchParameters;
+
+unsafe impl Callback for NewUrlLaunchParameters {
+    const ID: i32 = CALLBACK_BASE_ID + 14;
+    const SIZE: i32 = ::std::mem::size_of::<sys::NewUrlLaunchParameters_t>() as i32;
+
+    unsafe fn from_raw(_: *mut c_void) -> Self {
+        NewUrlLaunchParameters
+    }
+}
+
+/// Called when the play time of a timed trial changes, for example
+/// periodically while playing or when the trial runs out.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimedTrialStatus {
+    /// The app id of the trial
+    pub app_id: AppId,
+    /// Whether the trial is being played offline
+    pub is_offline: bool,
+    /// How many seconds the app can be played in total
+    pub seconds_allowed: u32,
+    /// How many seconds the app has already been played
+    pub seconds_played: u32,
+}
+
+unsafe impl Callback for TimedTrialStatus {
+    const ID: i32 = CALLBACK_BASE_ID + 30;
+    const SIZE: i32 = ::std::mem::size_of::<sys::TimedTrialStatus_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::TimedTrialStatus_t);
+        TimedTrialStatus {
+            app_id: AppId(val.m_unAppID),
+            is_offline: val.m_bIsOffline,
+            seconds_allowed: val.m_unSecondsAllowed,
+            seconds_played: val.m_unSecondsPlayed,
+        }
+    }
+}
+
+#[test]
+fn test_launch_intent_parse() {
+    assert_eq!(
+        LaunchIntent::parse("+connect_lobby 109775241021923649"),
+        LaunchIntent::JoinLobby(LobbyId(109775241021923649))
+    );
+    assert_eq!(
+        LaunchIntent::parse("-windowed +connect 127.0.0.1:27015"),
+        LaunchIntent::ConnectServer("127.0.0.1:27015".to_owned())
+    );
+    assert_eq!(
+        LaunchIntent::parse("+connect_lobby abc"),
+        LaunchIntent::None
+    );
+    assert_eq!(LaunchIntent::parse(""), LaunchIntent::None);
 }
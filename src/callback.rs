@@ -2,7 +2,12 @@ use super::*;
 
 use crate::sys;
 
-use std::sync::{Arc, Weak};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryIter};
+use std::sync::{Arc, TryLockError, Weak};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 pub unsafe trait Callback {
     const ID: i32;
@@ -13,24 +18,62 @@ pub unsafe trait Callback {
 /// A handle that can be used to remove a callback
 /// at a later point.
 ///
-/// Removes the callback when dropped
+/// Removes the callback when dropped, so the handle has to be kept alive
+/// for as long as the callback should be run. Other callbacks registered
+/// for the same type are not affected. The handle can be dropped from
+/// within any callback, including its own.
+#[must_use = "the callback is removed when the handle is dropped"]
 pub struct CallbackHandle<Manager = ClientManager> {
     id: i32,
+    token: u64,
     inner: Weak<Inner<Manager>>,
 }
 unsafe impl<Manager> Send for CallbackHandle<Manager> {}
 impl<Manager> CallbackHandle<Manager> {
+    /// Removes the callback, if it hasn't already been removed
     pub fn disconnect(&self) {
         if let Some(inner) = self.inner.upgrade() {
-            match inner.callbacks.lock() {
-                Ok(mut cb) => {
-                    cb.callbacks.remove(&self.id);
-                }
+            let removed = match inner.callbacks.lock() {
+                Ok(mut cb) => cb.remove_subscriber(self.id, self.token),
                 Err(err) => {
                     eprintln!("error while dropping callback: {:?}", err);
+                    None
                 }
-            }
+            };
+            // Dropped after unlocking, the callback may own other handles
+            drop(removed);
+        }
+    }
+
+    /// Removes the callback and consumes the handle.
+    ///
+    /// This is the same as dropping the handle.
+    pub fn unregister(self) {
+        drop(self)
+    }
+
+    /// Keeps the callback registered for the lifetime of the client
+    /// instead of removing it once the handle is dropped.
+    ///
+    /// This replaces the callback previously kept registered this way for
+    /// the same type, callbacks registered with handles are not affected.
+    pub(crate) fn forget(self) {
+        if let Some(inner) = self.inner.upgrade() {
+            let replaced = {
+                let mut cb = inner.callbacks.lock().unwrap();
+                cb.forgotten
+                    .insert(self.id, self.token)
+                    .and_then(|token| cb.remove_subscriber(self.id, token))
+            };
+            drop(replaced);
         }
+        std::mem::forget(self)
+    }
+}
+
+impl<Manager> Drop for CallbackHandle<Manager> {
+    fn drop(&mut self) {
+        self.disconnect();
     }
 }
 
@@ -42,33 +85,768 @@ where
     C: Callback,
     F: FnMut(C) + Send + 'static,
 {
+    static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+    let token = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed);
     {
         let mut callbacks = inner.callbacks.lock().unwrap();
-        callbacks.callbacks.insert(
-            C::ID,
-            Box::new(move |param| {
+        callbacks.callbacks.entry(C::ID).or_default().push((
+            token,
+            Arc::new(Mutex::new(Box::new(move |param| {
                 let param = C::from_raw(param);
                 f(param)
-            }),
-        );
+            }))),
+        ));
     }
     CallbackHandle {
         id: C::ID,
+        token,
         inner: Arc::downgrade(inner),
     }
 }
 
+/// A queue of callback events of a single type.
+///
+/// Instead of handling each event inside a closure, the events are
+/// buffered until they are taken out, e.g. once per frame. The stream is
+/// an iterator over the events received so far; once it returns `None`
+/// it can be iterated again after `run_callbacks` has delivered more
+/// events.
+///
+/// As with `register_callback`, other callbacks registered for the same
+/// type keep receiving the events. The callback is removed when the
+/// stream is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// # use steamworks::*;
+/// # let client = steamworks::Client::init().unwrap();
+/// let persona_changes = client.callback_stream::<PersonaStateChange>();
+/// loop {
+///     client.run_callbacks();
+///     for change in persona_changes.try_iter() {
+///         println!("{:?} changed", change.steam_id);
+///     }
+/// #   break;
+/// }
+/// ```
+pub struct CallbackStream<C, Manager = ClientManager> {
+    receiver: Receiver<C>,
+    _handle: CallbackHandle<Manager>,
+}
+
+impl<C, Manager> CallbackStream<C, Manager> {
+    /// Returns an iterator over the events received since the last time
+    /// the stream was drained
+    pub fn try_iter(&self) -> TryIter<'_, C> {
+        self.receiver.try_iter()
+    }
+}
+
+impl<C, Manager> Iterator for CallbackStream<C, Manager> {
+    type Item = C;
+
+    fn next(&mut self) -> Option<C> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+pub(crate) unsafe fn register_callback_stream<C, Manager>(
+    inner: &Arc<Inner<Manager>>,
+) -> CallbackStream<C, Manager>
+where
+    C: Callback + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let handle = register_callback(inner, move |v: C| {
+        let _ = sender.send(v);
+    });
+    CallbackStream {
+        receiver,
+        _handle: handle,
+    }
+}
+
+/// Runs the callbacks of a client or server.
+///
+/// The steam api expects callbacks to only be dispatched from one thread,
+/// so unlike `Client` the pump is neither `Send` nor `Sync` and only one
+/// can exist at a time. It should be kept on the thread that runs the game
+/// loop while clones of the client are used from other threads.
+///
+/// # Example
+///
+/// ```no_run
+/// # let client = steamworks::Client::init().unwrap();
+/// let pump = client.callback_pump().unwrap();
+/// let friends = client.clone();
+/// std::thread::spawn(move || println!("{}", friends.friends().name()));
+/// loop {
+///     pump.run_callbacks();
+/// #   break;
+/// }
+/// ```
+///
+/// The pump can't be moved to another thread:
+///
+/// ```compile_fail
+/// # let client = steamworks::Client::init().unwrap();
+/// let pump = client.callback_pump().unwrap();
+/// std::thread::spawn(move || pump.run_callbacks());
+/// ```
+pub struct CallbackPump<Manager = ClientManager> {
+    inner: Arc<Inner<Manager>>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<Manager: crate::Manager> CallbackPump<Manager> {
+    /// Runs any currently pending callbacks
+    ///
+    /// This runs all currently pending callbacks on the current
+    /// thread.
+    ///
+    /// This should be called frequently (e.g. once per a frame)
+    /// in order to reduce the latency between recieving events.
+    pub fn run_callbacks(&self) {
+        self.inner.run_callbacks()
+    }
+}
+
+impl<Manager> Drop for CallbackPump<Manager> {
+    fn drop(&mut self) {
+        self.inner.pump_taken.store(false, Ordering::Release);
+    }
+}
+
+pub(crate) fn take_callback_pump<Manager>(
+    inner: &Arc<Inner<Manager>>,
+) -> Option<CallbackPump<Manager>> {
+    if inner.pump_taken.swap(true, Ordering::AcqRel) {
+        return None;
+    }
+    Some(CallbackPump {
+        inner: inner.clone(),
+        _not_send: PhantomData,
+    })
+}
+
+/// Runs the callbacks through the pump, which is only released again once
+/// they have been run. Refuses to run them while the pump is held
+/// elsewhere (by a `CallbackPump`, a `CallbackThread` or another thread
+/// currently running them), as callbacks must only be run from one place.
+///
+/// Returns whether the callbacks were run.
+pub(crate) fn run_callbacks_through_pump<Manager: crate::Manager>(
+    inner: &Arc<Inner<Manager>>,
+) -> bool {
+    match take_callback_pump(inner) {
+        Some(pump) => {
+            pump.run_callbacks();
+            true
+        }
+        None => {
+            #[cfg(all(feature = "log", debug_assertions))]
+            log::debug!(
+                target: "steamworks",
+                "`run_callbacks` skipped, the callback pump is taken"
+            );
+            false
+        }
+    }
+}
+
+/// A thread that runs the callbacks of a client at a fixed interval.
+///
+/// Returned by `Client::spawn_callback_thread`. The thread holds the
+/// client's `CallbackPump` and is stopped and joined when this handle
+/// is dropped.
+pub struct CallbackThread {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl CallbackThread {
+    /// Stops the thread and waits for it to exit.
+    ///
+    /// This is the same as dropping the handle.
+    pub fn stop(self) {
+        drop(self)
+    }
+}
+
+impl Drop for CallbackThread {
+    fn drop(&mut self) {
+        // Dropping the sender wakes the thread up
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            // Joining from within a callback would never return
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+pub(crate) fn spawn_callback_thread<Manager>(
+    inner: &Arc<Inner<Manager>>,
+    interval: Duration,
+) -> Option<CallbackThread>
+where
+    Manager: crate::Manager + Send + Sync + 'static,
+{
+    // The pump is taken here instead of on the thread so that failing to
+    // take it can be reported to the caller, it's released again when the
+    // pump created on the thread is dropped
+    if inner.pump_taken.swap(true, Ordering::AcqRel) {
+        return None;
+    }
+    let inner = inner.clone();
+
+    let (stop, stopped) = mpsc::channel::<()>();
+    let thread = std::thread::Builder::new()
+        .name("steam callbacks".into())
+        .spawn(move || {
+            let pump = CallbackPump {
+                inner,
+                _not_send: PhantomData,
+            };
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                pump.run_callbacks();
+            }
+        })
+        .expect("failed to spawn the callback thread");
+    Some(CallbackThread {
+        stop: Some(stop),
+        thread: Some(thread),
+    })
+}
+
+/// Limits how long pending operations are waited for.
+///
+/// Set with `Client::set_operation_policy`, the timeout can be overridden
+/// for the calls made through an interface with its `with_call_options`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OperationPolicy {
+    /// How long call results may stay pending before they fail with
+    /// `SteamError::Timeout`.
+    ///
+    /// `None`, the default, waits until steam delivers the result.
+    /// Timeouts are checked while callbacks are being run.
+    pub call_result_timeout: Option<Duration>,
+    /// How long blocking inventory requests wait for steam, 10 seconds
+    /// by default
+    pub inventory_timeout: Duration,
+    /// How often blocking inventory requests check whether steam is done,
+    /// 100 milliseconds by default
+    pub inventory_poll_interval: Duration,
+}
+
+impl Default for OperationPolicy {
+    fn default() -> Self {
+        OperationPolicy {
+            call_result_timeout: None,
+            inventory_timeout: Duration::from_secs(10),
+            inventory_poll_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Cancels the pending operations it was passed to.
+///
+/// Clones cancel the same operations. Cancelled call results fail with
+/// `SteamError::Cancelled` the next time callbacks are run, and blocking
+/// inventory requests stop waiting with `InventoryError::Cancelled`.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a token that hasn't been cancelled
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Cancels the operations started with this token
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Returns whether `cancel` has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Options for the operations started through an interface, set with
+/// the `with_call_options` method of the interface.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use steamworks::*;
+/// # let client = steamworks::Client::init().unwrap();
+/// let token = CancellationToken::new();
+/// let options = CallOptions::new()
+///     .timeout(Duration::from_secs(5))
+///     .cancellation_token(token.clone());
+/// client
+///     .user_stats()
+///     .with_call_options(options)
+///     .find_leaderboard("high_scores", |result| {
+///         println!("{:?}", result);
+///     });
+/// // e.g. when leaving the screen that requested the leaderboard
+/// token.cancel();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CallOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) token: Option<CancellationToken>,
+}
+
+impl CallOptions {
+    pub fn new() -> CallOptions {
+        CallOptions::default()
+    }
+
+    /// Overrides the timeout of the `OperationPolicy`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Allows cancelling the operations with the given token
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+}
+
+macro_rules! call_options_interfaces {
+    ($($ty:ident,)*) => {
+        $(
+            impl<Manager> $ty<Manager> {
+                /// Applies the options to the operations started through
+                /// the returned interface
+                pub fn with_call_options(mut self, options: CallOptions) -> Self {
+                    self.options = options;
+                    self
+                }
+            }
+        )*
+    };
+}
+
+call_options_interfaces! {
+    Apps,
+    GameServerStats,
+    HTMLSurface,
+    Inventory,
+    Matchmaking,
+    Parties,
+    UGC,
+    User,
+    UserStats,
+}
+
+impl Callbacks {
+    /// Removes the callback registered with `token`, returning it so that
+    /// it can be dropped after the lock is released
+    fn remove_subscriber(&mut self, id: i32, token: u64) -> Option<Subscriber> {
+        let subscribers = self.callbacks.get_mut(&id)?;
+        let index = subscribers.iter().position(|(t, _)| *t == token)?;
+        let (_, subscriber) = subscribers.remove(index);
+        if subscribers.is_empty() {
+            self.callbacks.remove(&id);
+        }
+        Some(subscriber)
+    }
+
+    fn is_subscribed(&self, id: i32, token: u64) -> bool {
+        self.callbacks
+            .get(&id)
+            .is_some_and(|s| s.iter().any(|(t, _)| *t == token))
+    }
+}
+
+impl<Manager> Inner<Manager> {
+    /// Runs the callbacks registered for `id` with the raw event.
+    ///
+    /// The lock is only held while looking up the callbacks, so that they
+    /// can register and remove callbacks themselves.
+    pub(crate) unsafe fn dispatch_callback(&self, id: i32, param: *mut c_void) {
+        let subscribers = match self.callbacks.lock().unwrap().callbacks.get(&id) {
+            Some(subscribers) => subscribers.clone(),
+            None => return,
+        };
+        for (token, subscriber) in subscribers {
+            // Removed by one of the callbacks run before it
+            if !self.callbacks.lock().unwrap().is_subscribed(id, token) {
+                continue;
+            }
+            let mut f = match subscriber.try_lock() {
+                Ok(f) => f,
+                Err(TryLockError::Poisoned(err)) => err.into_inner(),
+                // Callbacks being run from within the same callback
+                Err(TryLockError::WouldBlock) => continue,
+            };
+            f(param);
+        }
+    }
+}
+
+/// A call result waiting for steam to deliver it
+pub(crate) struct PendingCallResult {
+    /// Called with the raw result, or with a zeroed one and the reason the
+    /// call failed
+    pub(crate) f: Box<dyn FnOnce(*mut c_void, Option<SteamError>) + Send + 'static>,
+    size: usize,
+    deadline: Option<Instant>,
+    token: Option<CancellationToken>,
+}
+
+impl PendingCallResult {
+    fn is_cancelled(&self) -> bool {
+        self.token.as_ref().is_some_and(|t| t.is_cancelled())
+    }
+
+    /// Calls the callback with the error and a zeroed result, like the
+    /// buffer of a result steam failed to deliver
+    pub(crate) fn fail(self, err: SteamError) {
+        let mut raw = vec![0u64; (self.size + 7) / 8];
+        (self.f)(raw.as_mut_ptr() as *mut c_void, Some(err));
+    }
+}
+
+impl<Manager> Inner<Manager> {
+    /// Fails the call results that have timed out or were cancelled
+    pub(crate) fn expire_call_results(&self) {
+        let expired: Vec<PendingCallResult> = {
+            let mut callbacks = self.callbacks.lock().unwrap();
+            let now = Instant::now();
+            let ids: Vec<_> = callbacks
+                .call_results
+                .iter()
+                .filter(|(_, p)| p.is_cancelled() || p.deadline.is_some_and(|d| d <= now))
+                .map(|(id, _)| *id)
+                .collect();
+            ids.iter()
+                .filter_map(|id| callbacks.call_results.remove(id))
+                .collect()
+        };
+        // Called without the lock held so that the callbacks can start
+        // new calls
+        for pending in expired {
+            if pending.is_cancelled() {
+                pending.fail(SteamError::Cancelled);
+            } else {
+                pending.fail(SteamError::Timeout);
+            }
+        }
+    }
+}
+
 pub(crate) unsafe fn register_call_result<C, F, Manager>(
     inner: &Arc<Inner<Manager>>,
+    options: &CallOptions,
     api_call: sys::SteamAPICall_t,
     _callback_id: i32,
     f: F,
 ) where
-    F: for<'a> FnOnce(&'a C, bool) + 'static + Send,
+    F: for<'a> FnOnce(&'a C, Option<SteamError>) + 'static + Send,
 {
     let mut callbacks = inner.callbacks.lock().unwrap();
+    let timeout = options.timeout.or(callbacks.policy.call_result_timeout);
     callbacks.call_results.insert(
         api_call,
-        Box::new(move |param, failed| f(&*(param as *const C), failed)),
+        PendingCallResult {
+            f: Box::new(move |param, failed| f(&*(param as *const C), failed)),
+            size: std::mem::size_of::<C>(),
+            deadline: timeout.map(|timeout| Instant::now() + timeout),
+            token: options.token.clone(),
+        },
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestManager;
+
+    unsafe impl Manager for TestManager {
+        unsafe fn get_pipe() -> sys::HSteamPipe {
+            0
+        }
+    }
+
+    fn test_inner() -> Arc<Inner<TestManager>> {
+        Arc::new(Inner {
+            _manager: TestManager,
+            callbacks: Mutex::new(Callbacks {
+                callbacks: HashMap::new(),
+                forgotten: HashMap::new(),
+                call_results: HashMap::new(),
+                policy: Default::default(),
+            }),
+            pump_taken: AtomicBool::new(false),
+            networking_sockets_data: Mutex::new(NetworkingSocketsData {
+                sockets: Default::default(),
+                independent_connections: Default::default(),
+                connection_callback: Default::default(),
+            }),
+        })
+    }
+
+    fn dispatch<C: Callback>(inner: &Inner<TestManager>, raw: *mut c_void) {
+        unsafe { inner.dispatch_callback(C::ID, raw) }
+    }
+
+    fn disconnected() -> sys::SteamServersDisconnected_t {
+        sys::SteamServersDisconnected_t {
+            m_eResult: sys::EResult::k_EResultNoConnection,
+        }
+    }
+
+    #[test]
+    fn test_callback_stream() {
+        let inner = test_inner();
+        let mut stream = unsafe { register_callback_stream::<SteamServersDisconnected, _>(&inner) };
+        assert!(stream.next().is_none());
+
+        let mut raw = disconnected();
+        dispatch::<SteamServersDisconnected>(&inner, &mut raw as *mut _ as *mut c_void);
+        dispatch::<SteamServersDisconnected>(&inner, &mut raw as *mut _ as *mut c_void);
+
+        assert_eq!(stream.try_iter().count(), 2);
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn test_callback_handle_drop() {
+        let inner = test_inner();
+        let handle = unsafe { register_callback(&inner, |_: SteamServersDisconnected| {}) };
+        assert!(inner
+            .callbacks
+            .lock()
+            .unwrap()
+            .callbacks
+            .contains_key(&SteamServersDisconnected::ID));
+        drop(handle);
+        assert!(!inner
+            .callbacks
+            .lock()
+            .unwrap()
+            .callbacks
+            .contains_key(&SteamServersDisconnected::ID));
+    }
+
+    #[test]
+    fn test_callback_handles_independent() {
+        let inner = test_inner();
+        let count = Arc::new(AtomicU64::new(0));
+        let old = {
+            let count = count.clone();
+            unsafe {
+                register_callback(&inner, move |_: SteamServersDisconnected| {
+                    count.fetch_add(1, Ordering::SeqCst);
+                })
+            }
+        };
+        let new = unsafe { register_callback_stream::<SteamServersDisconnected, _>(&inner) };
+
+        let mut raw = disconnected();
+        dispatch::<SteamServersDisconnected>(&inner, &mut raw as *mut _ as *mut c_void);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(new.try_iter().count(), 1);
+
+        old.unregister();
+        dispatch::<SteamServersDisconnected>(&inner, &mut raw as *mut _ as *mut c_void);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(new.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn test_callback_forget_replaces_forgotten() {
+        let inner = test_inner();
+        let stream = unsafe { register_callback_stream::<SteamServersDisconnected, _>(&inner) };
+        let count = Arc::new(AtomicU64::new(0));
+        for _ in 0..2 {
+            let count = count.clone();
+            unsafe {
+                register_callback(&inner, move |_: SteamServersDisconnected| {
+                    count.fetch_add(1, Ordering::SeqCst);
+                })
+                .forget();
+            }
+        }
+
+        let mut raw = disconnected();
+        dispatch::<SteamServersDisconnected>(&inner, &mut raw as *mut _ as *mut c_void);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(stream.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn test_callback_handle_dropped_in_callback() {
+        let inner = test_inner();
+        let slot = Arc::new(Mutex::new(None::<CallbackHandle<TestManager>>));
+        let count = Arc::new(AtomicU64::new(0));
+        let handle = {
+            let slot = slot.clone();
+            let count = count.clone();
+            unsafe {
+                register_callback(&inner, move |_: SteamServersDisconnected| {
+                    count.fetch_add(1, Ordering::SeqCst);
+                    drop(slot.lock().unwrap().take());
+                })
+            }
+        };
+        *slot.lock().unwrap() = Some(handle);
+
+        let mut raw = disconnected();
+        dispatch::<SteamServersDisconnected>(&inner, &mut raw as *mut _ as *mut c_void);
+        dispatch::<SteamServersDisconnected>(&inner, &mut raw as *mut _ as *mut c_void);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert!(inner.callbacks.lock().unwrap().callbacks.is_empty());
+    }
+
+    #[test]
+    fn test_callback_pump_taken_once() {
+        let inner = test_inner();
+        let pump = take_callback_pump(&inner);
+        assert!(pump.is_some());
+        assert!(take_callback_pump(&inner).is_none());
+        drop(pump);
+        assert!(take_callback_pump(&inner).is_some());
+    }
+
+    #[test]
+    fn test_run_callbacks_refused_while_pump_taken() {
+        let inner = test_inner();
+        let _pump = take_callback_pump(&inner).unwrap();
+        // Must not dispatch, the test manager's pipe isn't valid
+        assert!(!run_callbacks_through_pump(&inner));
+    }
+
+    #[test]
+    fn test_callback_thread_holds_pump() {
+        let inner = test_inner();
+        // The test manager's pipe isn't valid, so don't let the thread run
+        let thread = spawn_callback_thread(&inner, Duration::from_secs(3600));
+        assert!(thread.is_some());
+        assert!(take_callback_pump(&inner).is_none());
+        assert!(spawn_callback_thread(&inner, Duration::from_secs(3600)).is_none());
+        drop(thread);
+        assert!(take_callback_pump(&inner).is_some());
+    }
+
+    #[test]
+    fn test_call_result_timeout() {
+        let inner = test_inner();
+        let failure = Arc::new(Mutex::new(None));
+        {
+            let failure = failure.clone();
+            let options = CallOptions::new().timeout(Duration::ZERO);
+            unsafe {
+                register_call_result::<sys::LobbyCreated_t, _, _>(
+                    &inner,
+                    &options,
+                    1,
+                    0,
+                    move |_, f| {
+                        *failure.lock().unwrap() = f;
+                    },
+                );
+            }
+        }
+        unsafe {
+            register_call_result::<sys::LobbyCreated_t, _, _>(
+                &inner,
+                &CallOptions::default(),
+                2,
+                0,
+                |_, _| {},
+            )
+        };
+
+        inner.expire_call_results();
+        assert!(matches!(
+            *failure.lock().unwrap(),
+            Some(SteamError::Timeout)
+        ));
+        let callbacks = inner.callbacks.lock().unwrap();
+        assert!(!callbacks.call_results.contains_key(&1));
+        assert!(callbacks.call_results.contains_key(&2));
+    }
+
+    #[test]
+    fn test_call_result_cancel() {
+        let inner = test_inner();
+        let token = CancellationToken::new();
+        let failure = Arc::new(Mutex::new(None));
+        {
+            let failure = failure.clone();
+            let options = CallOptions::new().cancellation_token(token.clone());
+            unsafe {
+                register_call_result::<sys::LobbyCreated_t, _, _>(
+                    &inner,
+                    &options,
+                    1,
+                    0,
+                    move |_, f| {
+                        *failure.lock().unwrap() = f;
+                    },
+                );
+            }
+        }
+        inner.expire_call_results();
+        assert!(inner
+            .callbacks
+            .lock()
+            .unwrap()
+            .call_results
+            .contains_key(&1));
+
+        token.cancel();
+        inner.expire_call_results();
+        assert!(inner.callbacks.lock().unwrap().call_results.is_empty());
+        assert!(matches!(
+            *failure.lock().unwrap(),
+            Some(SteamError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn test_call_result_started_from_callback() {
+        let inner = test_inner();
+        let options = CallOptions::new().timeout(Duration::ZERO);
+        {
+            let inner_clone = inner.clone();
+            unsafe {
+                register_call_result::<sys::LobbyCreated_t, _, _>(
+                    &inner,
+                    &options,
+                    1,
+                    0,
+                    move |_, _| {
+                        register_call_result::<sys::LobbyCreated_t, _, _>(
+                            &inner_clone,
+                            &CallOptions::default(),
+                            2,
+                            0,
+                            |_, _| {},
+                        );
+                    },
+                );
+            }
+        }
+        inner.expire_call_results();
+        assert!(inner
+            .callbacks
+            .lock()
+            .unwrap()
+            .call_results
+            .contains_key(&2));
+    }
+}
@@ -11,6 +11,9 @@ use std::{
 ///
 /// Documentation is based on official documentation which doesn't
 /// always explain when an error could be returned or its meaning.
+///
+/// Each of steam's `EResult` codes maps to a variant, subsystem errors
+/// such as `InventoryError` wrap this type when steam reports one.
 #[derive(Copy, Clone, Debug, Error, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SteamError {
@@ -385,6 +388,63 @@ pub enum SteamError {
     /// max network send size
     #[error("WG network send size exceeded")]
     WGNetworkSendExceeded,
+    /// Returned when the users aren't friends
+    #[error("the users are not friends")]
+    AccountNotFriends,
+    /// Returned when the account is limited and can't perform the action
+    #[error("the user account is limited")]
+    LimitedUserAccount,
+    /// Returned when the item can't be removed
+    #[error("the item can not be removed")]
+    CantRemoveItem,
+    /// Returned when the account has been deleted
+    #[error("the account has been deleted")]
+    AccountDeleted,
+    /// Returned when the user cancelled an existing license
+    #[error("the user cancelled an existing license")]
+    ExistingUserCancelledLicense,
+    /// Returned when the action can't be performed because of a community
+    /// cooldown
+    #[error("community cooldown in effect")]
+    CommunityCooldown,
+    /// Returned when no launcher was specified but a launcher was required
+    #[error("no launcher specified")]
+    NoLauncherSpecified,
+    /// Returned when the user must agree to the steam subscriber agreement first
+    #[error("must agree to the steam subscriber agreement")]
+    MustAgreeToSSA,
+    /// Returned when the launcher has been migrated to steam
+    #[error("the launcher was migrated to steam")]
+    LauncherMigrated,
+    /// Returned when the steam realm of the request doesn't match the
+    /// realm of the user
+    #[error("steam realm mismatch")]
+    SteamRealmMismatch,
+    /// Returned when a signature failed to verify
+    #[error("the signature is invalid")]
+    InvalidSignature,
+    /// Returned when steam failed to parse the input
+    #[error("failed to parse the input")]
+    ParseFailure,
+    /// Returned when the account doesn't have a verified phone number
+    #[error("no verified phone number")]
+    NoVerifiedPhone,
+    /// Returned when the device's battery is too low for the action
+    #[error("insufficient battery")]
+    InsufficientBattery,
+    /// Returned when the device must be charging for the action
+    #[error("a charger is required")]
+    ChargerRequired,
+    /// Returned when the cached credential is invalid and the user has
+    /// to log in again
+    #[error("the cached credential is invalid")]
+    CachedCredentialInvalid,
+    /// Returned when the operation is not supported
+    #[error("the operation is not supported")]
+    NotSupported,
+    /// Returned when the steam family is already at its size limit
+    #[error("the family size limit has been exceeded")]
+    FamilySizeLimitExceeded,
 }
 
 impl From<sys::EResult> for SteamError {
@@ -519,7 +579,28 @@ impl From<sys::EResult> for SteamError {
             sys::EResult::k_EResultTooManyPending => SteamError::TooManyPending,
             sys::EResult::k_EResultNoSiteLicensesFound => SteamError::NoSiteLicensesFound,
             sys::EResult::k_EResultWGNetworkSendExceeded => SteamError::WGNetworkSendExceeded,
-            _ => unreachable!(),
+            sys::EResult::k_EResultAccountNotFriends => SteamError::AccountNotFriends,
+            sys::EResult::k_EResultLimitedUserAccount => SteamError::LimitedUserAccount,
+            sys::EResult::k_EResultCantRemoveItem => SteamError::CantRemoveItem,
+            sys::EResult::k_EResultAccountDeleted => SteamError::AccountDeleted,
+            sys::EResult::k_EResultExistingUserCancelledLicense => {
+                SteamError::ExistingUserCancelledLicense
+            }
+            sys::EResult::k_EResultCommunityCooldown => SteamError::CommunityCooldown,
+            sys::EResult::k_EResultNoLauncherSpecified => SteamError::NoLauncherSpecified,
+            sys::EResult::k_EResultMustAgreeToSSA => SteamError::MustAgreeToSSA,
+            sys::EResult::k_EResultLauncherMigrated => SteamError::LauncherMigrated,
+            sys::EResult::k_EResultSteamRealmMismatch => SteamError::SteamRealmMismatch,
+            sys::EResult::k_EResultInvalidSignature => SteamError::InvalidSignature,
+            sys::EResult::k_EResultParseFailure => SteamError::ParseFailure,
+            sys::EResult::k_EResultNoVerifiedPhone => SteamError::NoVerifiedPhone,
+            sys::EResult::k_EResultInsufficientBattery => SteamError::InsufficientBattery,
+            sys::EResult::k_EResultChargerRequired => SteamError::ChargerRequired,
+            sys::EResult::k_EResultCachedCredentialInvalid => SteamError::CachedCredentialInvalid,
+            sys::EResult::k_EResultNotSupported => SteamError::NotSupported,
+            sys::EResult::k_EResultFamilySizeLimitExceeded => SteamError::FamilySizeLimitExceeded,
+            // Newer codes that aren't known to this version of the crate
+            _ => SteamError::Generic,
         }
     }
 }
@@ -727,6 +808,46 @@ impl TryFrom<i64> for SteamError {
             x if x == sys::EResult::k_EResultWGNetworkSendExceeded as i64 => {
                 SteamError::WGNetworkSendExceeded
             }
+            x if x == sys::EResult::k_EResultAccountNotFriends as i64 => {
+                SteamError::AccountNotFriends
+            }
+            x if x == sys::EResult::k_EResultLimitedUserAccount as i64 => {
+                SteamError::LimitedUserAccount
+            }
+            x if x == sys::EResult::k_EResultCantRemoveItem as i64 => SteamError::CantRemoveItem,
+            x if x == sys::EResult::k_EResultAccountDeleted as i64 => SteamError::AccountDeleted,
+            x if x == sys::EResult::k_EResultExistingUserCancelledLicense as i64 => {
+                SteamError::ExistingUserCancelledLicense
+            }
+            x if x == sys::EResult::k_EResultCommunityCooldown as i64 => {
+                SteamError::CommunityCooldown
+            }
+            x if x == sys::EResult::k_EResultNoLauncherSpecified as i64 => {
+                SteamError::NoLauncherSpecified
+            }
+            x if x == sys::EResult::k_EResultMustAgreeToSSA as i64 => SteamError::MustAgreeToSSA,
+            x if x == sys::EResult::k_EResultLauncherMigrated as i64 => {
+                SteamError::LauncherMigrated
+            }
+            x if x == sys::EResult::k_EResultSteamRealmMismatch as i64 => {
+                SteamError::SteamRealmMismatch
+            }
+            x if x == sys::EResult::k_EResultInvalidSignature as i64 => {
+                SteamError::InvalidSignature
+            }
+            x if x == sys::EResult::k_EResultParseFailure as i64 => SteamError::ParseFailure,
+            x if x == sys::EResult::k_EResultNoVerifiedPhone as i64 => SteamError::NoVerifiedPhone,
+            x if x == sys::EResult::k_EResultInsufficientBattery as i64 => {
+                SteamError::InsufficientBattery
+            }
+            x if x == sys::EResult::k_EResultChargerRequired as i64 => SteamError::ChargerRequired,
+            x if x == sys::EResult::k_EResultCachedCredentialInvalid as i64 => {
+                SteamError::CachedCredentialInvalid
+            }
+            x if x == sys::EResult::k_EResultNotSupported as i64 => SteamError::NotSupported,
+            x if x == sys::EResult::k_EResultFamilySizeLimitExceeded as i64 => {
+                SteamError::FamilySizeLimitExceeded
+            }
             _ => return Err(InvalidErrorCode),
         };
         Ok(error)
@@ -737,16 +858,22 @@ impl TryFrom<i64> for SteamError {
 #[error("error code could not be converted to rust enum")]
 pub struct InvalidErrorCode;
 
+/// Returned when a string isn't a valid steam id in any of the
+/// formats accepted by `SteamId::from_str`
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("string could not be parsed as a steam id")]
+pub struct ParseSteamIdError;
+
 #[derive(Clone, Debug, Error, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SteamAPIInitError {
-    #[error("Some other failure")]
+    #[error("Some other failure: {0}")]
     FailedGeneric(String),
 
-    #[error("We cannot connect to Steam, steam probably isn't running")]
+    #[error("We cannot connect to Steam, steam probably isn't running: {0}")]
     NoSteamClient(String),
 
-    #[error("Steam client appears to be out of date")]
+    #[error("Steam client appears to be out of date: {0}")]
     VersionMismatch(String),
 }
 
@@ -773,4 +900,37 @@ impl SteamAPIInitError {
             _ => unreachable!(),
         }
     }
+
+    /// Returns the error message reported by the steamworks sdk
+    pub fn message(&self) -> &str {
+        match self {
+            SteamAPIInitError::FailedGeneric(msg)
+            | SteamAPIInitError::NoSteamClient(msg)
+            | SteamAPIInitError::VersionMismatch(msg) => msg,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eresult_codes() {
+        assert_eq!(
+            SteamError::from(sys::EResult::k_EResultNotSupported),
+            SteamError::NotSupported
+        );
+        assert_eq!(
+            SteamError::try_from(sys::EResult::k_EResultFamilySizeLimitExceeded as i64).unwrap(),
+            SteamError::FamilySizeLimitExceeded
+        );
+        // Every error code converts the same way from both representations
+        for code in 2..=129 {
+            if let Ok(err) = SteamError::try_from(code) {
+                let raw: sys::EResult = unsafe { std::mem::transmute(code as u32) };
+                assert_eq!(SteamError::from(raw), err, "code {}", code);
+            }
+        }
+    }
 }
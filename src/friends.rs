@@ -278,10 +278,19 @@ unsafe impl Callback for PersonaStateChange {
     }
 }
 
+/// Called when the steam overlay is opened or closed
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GameOverlayActivated {
+    /// Whether the overlay is now active
     pub active: bool,
+    /// Whether the user opened the overlay, rather than e.g. the game
+    /// opening a web page in it
+    pub user_initiated: bool,
+    /// The app the overlay was opened for
+    pub app_id: AppId,
+    /// The process id of the overlay
+    pub overlay_pid: u32,
 }
 
 unsafe impl Callback for GameOverlayActivated {
@@ -291,7 +300,10 @@ unsafe impl Callback for GameOverlayActivated {
     unsafe fn from_raw(raw: *mut c_void) -> Self {
         let val = &mut *(raw as *mut sys::GameOverlayActivated_t);
         Self {
-            active: val.m_bActive == 1,
+            active: val.m_bActive != 0,
+            user_initiated: val.m_bUserInitiated,
+            app_id: AppId(val.m_nAppID),
+            overlay_pid: val.m_dwOverlayPID,
         }
     }
 }
@@ -405,71 +417,46 @@ impl<Manager> Friend<Manager> {
 
     /// Returns a small (32x32) avatar for the user in RGBA format
     pub fn small_avatar(&self) -> Option<Vec<u8>> {
+        self.small_avatar_handle()?.rgba()
+    }
+
+    /// Returns a handle to the small (32x32) avatar for the user
+    pub fn small_avatar_handle(&self) -> Option<ImageHandle> {
         unsafe {
-            let utils = sys::SteamAPI_SteamUtils_v010();
-            let img = sys::SteamAPI_ISteamFriends_GetSmallFriendAvatar(self.friends, self.id.0);
-            if img == 0 {
-                return None;
-            }
-            let mut width = 0;
-            let mut height = 0;
-            if !sys::SteamAPI_ISteamUtils_GetImageSize(utils, img, &mut width, &mut height) {
-                return None;
-            }
-            assert_eq!(width, 32);
-            assert_eq!(height, 32);
-            let mut dest = vec![0; 32 * 32 * 4];
-            if !sys::SteamAPI_ISteamUtils_GetImageRGBA(utils, img, dest.as_mut_ptr(), 32 * 32 * 4) {
-                return None;
-            }
-            Some(dest)
+            ImageHandle::from_raw(sys::SteamAPI_ISteamFriends_GetSmallFriendAvatar(
+                self.friends,
+                self.id.0,
+            ))
         }
     }
 
     /// Returns a medium (64x64) avatar for the user in RGBA format
     pub fn medium_avatar(&self) -> Option<Vec<u8>> {
+        self.medium_avatar_handle()?.rgba()
+    }
+
+    /// Returns a handle to the medium (64x64) avatar for the user
+    pub fn medium_avatar_handle(&self) -> Option<ImageHandle> {
         unsafe {
-            let utils = sys::SteamAPI_SteamUtils_v010();
-            let img = sys::SteamAPI_ISteamFriends_GetMediumFriendAvatar(self.friends, self.id.0);
-            if img == 0 {
-                return None;
-            }
-            let mut width = 0;
-            let mut height = 0;
-            if !sys::SteamAPI_ISteamUtils_GetImageSize(utils, img, &mut width, &mut height) {
-                return None;
-            }
-            assert_eq!(width, 64);
-            assert_eq!(height, 64);
-            let mut dest = vec![0; 64 * 64 * 4];
-            if !sys::SteamAPI_ISteamUtils_GetImageRGBA(utils, img, dest.as_mut_ptr(), 64 * 64 * 4) {
-                return None;
-            }
-            Some(dest)
+            ImageHandle::from_raw(sys::SteamAPI_ISteamFriends_GetMediumFriendAvatar(
+                self.friends,
+                self.id.0,
+            ))
         }
     }
 
     /// Returns a large (184x184) avatar for the user in RGBA format
     pub fn large_avatar(&self) -> Option<Vec<u8>> {
+        self.large_avatar_handle()?.rgba()
+    }
+
+    /// Returns a handle to the large (184x184) avatar for the user
+    pub fn large_avatar_handle(&self) -> Option<ImageHandle> {
         unsafe {
-            let utils = sys::SteamAPI_SteamUtils_v010();
-            let img = sys::SteamAPI_ISteamFriends_GetLargeFriendAvatar(self.friends, self.id.0);
-            if img == 0 {
-                return None;
-            }
-            let mut width = 0;
-            let mut height = 0;
-            if !sys::SteamAPI_ISteamUtils_GetImageSize(utils, img, &mut width, &mut height) {
-                return None;
-            }
-            assert_eq!(width, 184);
-            assert_eq!(height, 184);
-            let mut dest = vec![0; 184 * 184 * 4];
-            if !sys::SteamAPI_ISteamUtils_GetImageRGBA(utils, img, dest.as_mut_ptr(), 184 * 184 * 4)
-            {
-                return None;
-            }
-            Some(dest)
+            ImageHandle::from_raw(sys::SteamAPI_ISteamFriends_GetLargeFriendAvatar(
+                self.friends,
+                self.id.0,
+            ))
         }
     }
 
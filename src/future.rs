@@ -0,0 +1,168 @@
+use crate::SteamError;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A future that resolves once steam has delivered the result of an
+/// asynchronous call.
+///
+/// Like the callback based functions, the result is only delivered while
+/// `Client::run_callbacks` is being called, so the callbacks must keep
+/// being run (e.g. from the game loop or a separate task) for the future
+/// to complete.
+#[must_use = "futures do nothing unless polled"]
+pub struct CallResultFuture<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+/// Completes the `CallResultFuture` it was created with.
+///
+/// Returned by `call_result_future`. Dropping the completer without
+/// completing it, e.g. when the call result is dropped, resolves the
+/// future to `FromCancelled::cancelled`.
+pub struct CallResultCompleter<T: FromCancelled> {
+    state: Option<Arc<Mutex<State<T>>>>,
+}
+
+/// The value a `CallResultFuture` resolves to if its completer is dropped
+/// without being completed
+pub trait FromCancelled {
+    fn cancelled() -> Self;
+}
+
+impl<T> FromCancelled for Result<T, SteamError> {
+    fn cancelled() -> Self {
+        Err(SteamError::Cancelled)
+    }
+}
+
+impl<T> FromCancelled for Result<T, ()> {
+    fn cancelled() -> Self {
+        Err(())
+    }
+}
+
+struct State<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// Turns a callback based function into a future.
+///
+/// `start` is called immediately with a completer that should be
+/// completed from the callback.
+///
+/// # Example
+///
+/// ```no_run
+/// # let client = steamworks::Client::init().unwrap();
+/// let user_stats = client.user_stats();
+/// let leaderboard = steamworks::call_result_future(|c| {
+///     user_stats.find_leaderboard("high_scores", move |v| c.complete(v))
+/// });
+/// ```
+pub fn call_result_future<T, F>(start: F) -> CallResultFuture<T>
+where
+    T: FromCancelled,
+    F: FnOnce(CallResultCompleter<T>),
+{
+    let state = Arc::new(Mutex::new(State {
+        value: None,
+        waker: None,
+    }));
+    start(CallResultCompleter {
+        state: Some(state.clone()),
+    });
+    CallResultFuture { state }
+}
+
+impl<T: FromCancelled> CallResultCompleter<T> {
+    /// Completes the future with the given value
+    pub fn complete(mut self, value: T) {
+        if let Some(state) = self.state.take() {
+            finish(&state, value);
+        }
+    }
+}
+
+impl<T: FromCancelled> Drop for CallResultCompleter<T> {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.take() {
+            finish(&state, T::cancelled());
+        }
+    }
+}
+
+fn finish<T>(state: &Mutex<State<T>>, value: T) {
+    let waker = {
+        let mut state = state.lock().unwrap();
+        state.value = Some(value);
+        state.waker.take()
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+impl<T> Future for CallResultFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap();
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::Wake;
+
+    struct CountingWaker(Mutex<u32>);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            *self.0.lock().unwrap() += 1;
+        }
+    }
+
+    #[test]
+    fn test_call_result_future() {
+        let waker = Arc::new(CountingWaker(Mutex::new(0)));
+        let task_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&task_waker);
+
+        let mut completer = None;
+        let mut future = call_result_future(|c| completer = Some(c));
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+
+        completer.unwrap().complete(Ok::<_, ()>(5));
+        assert_eq!(*waker.0.lock().unwrap(), 1);
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(Ok(5)));
+    }
+
+    #[test]
+    fn test_call_result_future_dropped() {
+        let waker = Arc::new(CountingWaker(Mutex::new(0)));
+        let task_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&task_waker);
+
+        let mut completer = None;
+        let mut future = call_result_future(|c| completer = Some(c));
+        assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+
+        drop(completer);
+        assert_eq!(*waker.0.lock().unwrap(), 1);
+        match Pin::new(&mut future).poll(&mut cx) {
+            Poll::Ready(Err::<(), _>(SteamError::Cancelled)) => {}
+            _ => panic!("expected the future to be cancelled"),
+        }
+    }
+}
@@ -0,0 +1,521 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 5200;
+
+/// Access to the steam game search interface.
+///
+/// Game search matches players (optionally as a lobby) with game hosts
+/// that are looking for players, as an alternative to browsing a list of
+/// lobbies.
+pub struct GameSearch<Manager> {
+    pub(crate) search: *mut sys::ISteamGameSearch,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// Identifies a game hosted through `GameSearch::request_players_for_game`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UniqueGameId(pub(crate) u64);
+
+impl UniqueGameId {
+    /// Creates a `UniqueGameId` from a raw 64 bit value.
+    pub fn from_raw(id: u64) -> UniqueGameId {
+        UniqueGameId(id)
+    }
+
+    /// Returns the raw 64 bit value of the game id
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Errors from the game search functions
+#[derive(Clone, Debug, Error)]
+pub enum GameSearchError {
+    /// A search is already in progress
+    #[error("a search is already in progress")]
+    SearchAlreadyInProgress,
+    /// There is no search in progress
+    #[error("no search in progress")]
+    NoSearchInProgress,
+    /// Only the owner of the lobby can start a search with it
+    #[error("not the lobby leader")]
+    NotLobbyLeader,
+    /// No host was available to match the search
+    #[error("no host available")]
+    NoHostAvailable,
+    /// The search parameters are invalid
+    #[error("invalid search parameters")]
+    SearchParamsInvalid,
+    /// The user is offline
+    #[error("offline")]
+    Offline,
+    /// The user isn't authorized to use game search
+    #[error("not authorized")]
+    NotAuthorized,
+    /// An unknown error occurred
+    #[error("unknown error")]
+    Unknown,
+}
+
+fn game_search_result(code: sys::EGameSearchErrorCode_t) -> Result<(), GameSearchError> {
+    use sys::EGameSearchErrorCode_t::*;
+    Err(match code {
+        k_EGameSearchErrorCode_OK => return Ok(()),
+        k_EGameSearchErrorCode_Failed_Search_Already_In_Progress => {
+            GameSearchError::SearchAlreadyInProgress
+        }
+        k_EGameSearchErrorCode_Failed_No_Search_In_Progress => GameSearchError::NoSearchInProgress,
+        k_EGameSearchErrorCode_Failed_Not_Lobby_Leader => GameSearchError::NotLobbyLeader,
+        k_EGameSearchErrorCode_Failed_No_Host_Available => GameSearchError::NoHostAvailable,
+        k_EGameSearchErrorCode_Failed_Search_Params_Invalid => GameSearchError::SearchParamsInvalid,
+        k_EGameSearchErrorCode_Failed_Offline => GameSearchError::Offline,
+        k_EGameSearchErrorCode_Failed_NotAuthorized => GameSearchError::NotAuthorized,
+        _ => GameSearchError::Unknown,
+    })
+}
+
+/// The outcome of a game for a player, reported by the host with
+/// `GameSearch::submit_player_result`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlayerResult {
+    FailedToConnect,
+    Abandoned,
+    Kicked,
+    Incomplete,
+    Completed,
+}
+
+impl From<PlayerResult> for sys::EPlayerResult_t {
+    fn from(result: PlayerResult) -> Self {
+        match result {
+            PlayerResult::FailedToConnect => sys::EPlayerResult_t::k_EPlayerResultFailedToConnect,
+            PlayerResult::Abandoned => sys::EPlayerResult_t::k_EPlayerResultAbandoned,
+            PlayerResult::Kicked => sys::EPlayerResult_t::k_EPlayerResultKicked,
+            PlayerResult::Incomplete => sys::EPlayerResult_t::k_EPlayerResultIncomplete,
+            PlayerResult::Completed => sys::EPlayerResult_t::k_EPlayerResultCompleted,
+        }
+    }
+}
+
+impl<Manager> GameSearch<Manager> {
+    /// Adds a key that a host must match, along with the values that are
+    /// accepted for it.
+    ///
+    /// This must be called before starting a search.
+    pub fn add_game_search_params(
+        &self,
+        key: &str,
+        values: &[&str],
+    ) -> Result<(), GameSearchError> {
+        unsafe {
+            let key = CString::new(key).unwrap();
+            let values = CString::new(values.join(",")).unwrap();
+            game_search_result(sys::SteamAPI_ISteamGameSearch_AddGameSearchParams(
+                self.search,
+                key.as_ptr(),
+                values.as_ptr(),
+            ))
+        }
+    }
+
+    /// Searches for a game for all members of the lobby. Only the owner of
+    /// the lobby can start a search.
+    ///
+    /// The progress is reported through `SearchForGameProgress` and
+    /// `SearchForGameResult` callbacks.
+    pub fn search_for_game_with_lobby(
+        &self,
+        lobby: LobbyId,
+        player_min: i32,
+        player_max: i32,
+    ) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SearchForGameWithLobby(
+                self.search,
+                lobby.raw(),
+                player_min,
+                player_max,
+            ))
+        }
+    }
+
+    /// Searches for a game for the current user alone.
+    ///
+    /// The progress is reported through `SearchForGameProgress` and
+    /// `SearchForGameResult` callbacks.
+    pub fn search_for_game_solo(
+        &self,
+        player_min: i32,
+        player_max: i32,
+    ) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SearchForGameSolo(
+                self.search,
+                player_min,
+                player_max,
+            ))
+        }
+    }
+
+    /// Accepts the game found by the search
+    pub fn accept_game(&self) -> Result<(), GameSearchError> {
+        unsafe { game_search_result(sys::SteamAPI_ISteamGameSearch_AcceptGame(self.search)) }
+    }
+
+    /// Declines the game found by the search
+    pub fn decline_game(&self) -> Result<(), GameSearchError> {
+        unsafe { game_search_result(sys::SteamAPI_ISteamGameSearch_DeclineGame(self.search)) }
+    }
+
+    /// Returns the connection details the host set with
+    /// `set_connection_details`, once the host has started the game.
+    pub fn retrieve_connection_details(&self, host: SteamId) -> Result<String, GameSearchError> {
+        unsafe {
+            let mut details = [0 as c_char; 1024];
+            game_search_result(sys::SteamAPI_ISteamGameSearch_RetrieveConnectionDetails(
+                self.search,
+                host.0,
+                details.as_mut_ptr(),
+                details.len() as _,
+            ))?;
+            Ok(CStr::from_ptr(details.as_ptr())
+                .to_string_lossy()
+                .into_owned())
+        }
+    }
+
+    /// Leaves the queue of players searching for a game
+    pub fn end_game_search(&self) -> Result<(), GameSearchError> {
+        unsafe { game_search_result(sys::SteamAPI_ISteamGameSearch_EndGameSearch(self.search)) }
+    }
+
+    /// Sets a key/value pair describing the game hosted by the user,
+    /// used to match the search params of players.
+    pub fn set_game_host_params(&self, key: &str, value: &str) -> Result<(), GameSearchError> {
+        unsafe {
+            let key = CString::new(key).unwrap();
+            let value = CString::new(value).unwrap();
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SetGameHostParams(
+                self.search,
+                key.as_ptr(),
+                value.as_ptr(),
+            ))
+        }
+    }
+
+    /// Sets the details players use to connect to the hosted game once it
+    /// has started, e.g. an address.
+    pub fn set_connection_details(&self, details: &str) -> Result<(), GameSearchError> {
+        unsafe {
+            let details = CString::new(details).unwrap();
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SetConnectionDetails(
+                self.search,
+                details.as_ptr(),
+                details.as_bytes_with_nul().len() as _,
+            ))
+        }
+    }
+
+    /// Marks the hosted game as looking for players.
+    ///
+    /// Lobbies with more than `max_team_size` members are not matched.
+    /// The players found are reported through `RequestPlayersForGameResult`
+    /// callbacks.
+    pub fn request_players_for_game(
+        &self,
+        player_min: i32,
+        player_max: i32,
+        max_team_size: i32,
+    ) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_RequestPlayersForGame(
+                self.search,
+                player_min,
+                player_max,
+                max_team_size,
+            ))
+        }
+    }
+
+    /// Accepts the players found for the game, releasing the connection
+    /// details to them.
+    pub fn host_confirm_game_start(
+        &self,
+        unique_game_id: UniqueGameId,
+    ) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_HostConfirmGameStart(
+                self.search,
+                unique_game_id.0,
+            ))
+        }
+    }
+
+    /// Stops looking for players for the hosted game
+    pub fn cancel_request_players_for_game(&self) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_CancelRequestPlayersForGame(
+                self.search,
+            ))
+        }
+    }
+
+    /// Reports the outcome of the game for a player
+    pub fn submit_player_result(
+        &self,
+        unique_game_id: UniqueGameId,
+        player: SteamId,
+        result: PlayerResult,
+    ) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SubmitPlayerResult(
+                self.search,
+                unique_game_id.0,
+                player.0,
+                result.into(),
+            ))
+        }
+    }
+
+    /// Marks the game as finished once all player results have been
+    /// submitted
+    pub fn end_game(&self, unique_game_id: UniqueGameId) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_EndGame(
+                self.search,
+                unique_game_id.0,
+            ))
+        }
+    }
+}
+
+/// Called while searching for a game with `search_for_game_with_lobby` or
+/// `search_for_game_solo`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SearchForGameProgress {
+    pub search_id: u64,
+    pub result: Result<(), SteamError>,
+    /// The lobby the search was started with, if any
+    pub lobby: LobbyId,
+    /// The user that ended the search, if it was ended
+    pub ended_search: SteamId,
+    pub seconds_remaining_estimate: i32,
+    pub players_searching: i32,
+}
+
+unsafe impl Callback for SearchForGameProgress {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SearchForGameProgressCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SearchForGameProgressCallback_t);
+        SearchForGameProgress {
+            search_id: val.m_ullSearchID,
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            lobby: LobbyId(val.m_lobbyID.m_steamid.m_unAll64Bits),
+            ended_search: SteamId(val.m_steamIDEndedSearch.m_steamid.m_unAll64Bits),
+            seconds_remaining_estimate: val.m_nSecondsRemainingEstimate,
+            players_searching: val.m_cPlayersSearching,
+        }
+    }
+}
+
+/// Called when a game has been found for the search, and again as players
+/// accept it
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SearchForGameResult {
+    pub search_id: u64,
+    pub result: Result<(), SteamError>,
+    pub players_in_game: i32,
+    pub players_accepted_game: i32,
+    /// The host of the game, used with `GameSearch::retrieve_connection_details`
+    pub host: SteamId,
+    /// Whether this is the last callback for the search
+    pub final_callback: bool,
+}
+
+unsafe impl Callback for SearchForGameResult {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SearchForGameResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SearchForGameResultCallback_t);
+        SearchForGameResult {
+            search_id: val.m_ullSearchID,
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            players_in_game: val.m_nCountPlayersInGame,
+            players_accepted_game: val.m_nCountAcceptedGame,
+            host: SteamId(val.m_steamIDHost.m_steamid.m_unAll64Bits),
+            final_callback: val.m_bFinalCallback,
+        }
+    }
+}
+
+/// Called when the search for players started by
+/// `request_players_for_game` has started or ended
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RequestPlayersForGameProgress {
+    pub result: Result<(), SteamError>,
+    pub search_id: u64,
+}
+
+unsafe impl Callback for RequestPlayersForGameProgress {
+    const ID: i32 = CALLBACK_BASE_ID + 11;
+    const SIZE: i32 = ::std::mem::size_of::<sys::RequestPlayersForGameProgressCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RequestPlayersForGameProgressCallback_t);
+        RequestPlayersForGameProgress {
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            search_id: val.m_ullSearchID,
+        }
+    }
+}
+
+/// Whether a player found for a hosted game has accepted it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlayerAcceptState {
+    Unknown,
+    Accepted,
+    Declined,
+}
+
+/// Called for every player found for the hosted game, and again when they
+/// accept or decline it
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RequestPlayersForGameResult {
+    pub result: Result<(), SteamError>,
+    pub search_id: u64,
+    pub player: SteamId,
+    pub lobby: LobbyId,
+    pub accept_state: PlayerAcceptState,
+    pub player_index: i32,
+    pub total_players_found: i32,
+    pub total_players_accepted_game: i32,
+    pub suggested_team_index: i32,
+    pub unique_game_id: UniqueGameId,
+}
+
+unsafe impl Callback for RequestPlayersForGameResult {
+    const ID: i32 = CALLBACK_BASE_ID + 12;
+    const SIZE: i32 = ::std::mem::size_of::<sys::RequestPlayersForGameResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RequestPlayersForGameResultCallback_t);
+        RequestPlayersForGameResult {
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            search_id: val.m_ullSearchID,
+            player: SteamId(val.m_SteamIDPlayerFound.m_steamid.m_unAll64Bits),
+            lobby: LobbyId(val.m_SteamIDLobby.m_steamid.m_unAll64Bits),
+            accept_state: match val.m_ePlayerAcceptState {
+                sys::RequestPlayersForGameResultCallback_t_PlayerAcceptState_t::k_EStatePlayerAccepted => {
+                    PlayerAcceptState::Accepted
+                }
+                sys::RequestPlayersForGameResultCallback_t_PlayerAcceptState_t::k_EStatePlayerDeclined => {
+                    PlayerAcceptState::Declined
+                }
+                _ => PlayerAcceptState::Unknown,
+            },
+            player_index: val.m_nPlayerIndex,
+            total_players_found: val.m_nTotalPlayersFound,
+            total_players_accepted_game: val.m_nTotalPlayersAcceptedGame,
+            suggested_team_index: val.m_nSuggestedTeamIndex,
+            unique_game_id: UniqueGameId(val.m_ullUniqueGameID),
+        }
+    }
+}
+
+/// Called once the search for players of the hosted game has completed
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RequestPlayersForGameFinalResult {
+    pub result: Result<(), SteamError>,
+    pub search_id: u64,
+    pub unique_game_id: UniqueGameId,
+}
+
+unsafe impl Callback for RequestPlayersForGameFinalResult {
+    const ID: i32 = CALLBACK_BASE_ID + 13;
+    const SIZE: i32 =
+        ::std::mem::size_of::<sys::RequestPlayersForGameFinalResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RequestPlayersForGameFinalResultCallback_t);
+        RequestPlayersForGameFinalResult {
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            search_id: val.m_ullSearchID,
+            unique_game_id: UniqueGameId(val.m_ullUniqueGameID),
+        }
+    }
+}
+
+/// Called in response to `GameSearch::submit_player_result`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SubmitPlayerResultResult {
+    pub result: Result<(), SteamError>,
+    pub unique_game_id: UniqueGameId,
+    pub player: SteamId,
+}
+
+unsafe impl Callback for SubmitPlayerResultResult {
+    const ID: i32 = CALLBACK_BASE_ID + 14;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SubmitPlayerResultResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SubmitPlayerResultResultCallback_t);
+        SubmitPlayerResultResult {
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            unique_game_id: UniqueGameId(val.ullUniqueGameID),
+            player: SteamId(val.steamIDPlayer.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
+/// Called in response to `GameSearch::end_game`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EndGameResult {
+    pub result: Result<(), SteamError>,
+    pub unique_game_id: UniqueGameId,
+}
+
+unsafe impl Callback for EndGameResult {
+    const ID: i32 = CALLBACK_BASE_ID + 15;
+    const SIZE: i32 = ::std::mem::size_of::<sys::EndGameResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::EndGameResultCallback_t);
+        EndGameResult {
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            unique_game_id: UniqueGameId(val.ullUniqueGameID),
+        }
+    }
+}
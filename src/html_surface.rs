@@ -0,0 +1,419 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 4500;
+
+/// Access to the steam HTML surface interface, an embedded browser
+/// that renders web pages into a pixel buffer.
+pub struct HTMLSurface<Manager> {
+    pub(crate) html: *mut sys::ISteamHTMLSurface,
+    pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
+}
+
+/// A handle to a browser created with `HTMLSurface::create_browser`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HTMLBrowser(pub(crate) sys::HHTMLBrowser);
+
+impl HTMLBrowser {
+    /// Returns the raw handle of the browser
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+impl<Manager> HTMLSurface<Manager> {
+    /// Initializes the HTML surface. Must be called before creating any
+    /// browsers.
+    pub fn init(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_Init(self.html) }
+    }
+
+    /// Shuts down the HTML surface, closing all browsers.
+    pub fn shutdown(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_Shutdown(self.html) }
+    }
+
+    /// Creates a new browser.
+    ///
+    /// The callback is called with the handle of the browser once it
+    /// is ready to load pages. `user_agent` is appended to the default
+    /// user agent and `user_css` is applied to every page.
+    pub fn create_browser<F>(&self, user_agent: Option<&str>, user_css: Option<&str>, cb: F)
+    where
+        F: FnOnce(Result<HTMLBrowser, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let user_agent = user_agent.map(|s| CString::new(s).unwrap());
+            let user_css = user_css.map(|s| CString::new(s).unwrap());
+            let api_call = sys::SteamAPI_ISteamHTMLSurface_CreateBrowser(
+                self.html,
+                user_agent.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()),
+                user_css.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()),
+            );
+            register_call_result::<sys::HTML_BrowserReady_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 1,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else {
+                        Ok(HTMLBrowser(v.unBrowserHandle))
+                    })
+                },
+            );
+        }
+    }
+
+    /// Closes a browser and frees its resources
+    pub fn remove_browser(&self, browser: HTMLBrowser) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_RemoveBrowser(self.html, browser.0) }
+    }
+
+    /// Navigates the browser to the given URL, optionally sending
+    /// `post_data` as a POST request.
+    pub fn load_url(&self, browser: HTMLBrowser, url: &str, post_data: Option<&str>) {
+        unsafe {
+            let url = CString::new(url).unwrap();
+            let post_data = post_data.map(|s| CString::new(s).unwrap());
+            sys::SteamAPI_ISteamHTMLSurface_LoadURL(
+                self.html,
+                browser.0,
+                url.as_ptr(),
+                post_data.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()),
+            )
+        }
+    }
+
+    /// Sets the size of the browser's surface in pixels
+    pub fn set_size(&self, browser: HTMLBrowser, width: u32, height: u32) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_SetSize(self.html, browser.0, width, height) }
+    }
+
+    /// Stops loading the current page
+    pub fn stop_load(&self, browser: HTMLBrowser) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_StopLoad(self.html, browser.0) }
+    }
+
+    /// Reloads the current page
+    pub fn reload(&self, browser: HTMLBrowser) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_Reload(self.html, browser.0) }
+    }
+
+    /// Navigates back in the browser's history
+    pub fn go_back(&self, browser: HTMLBrowser) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_GoBack(self.html, browser.0) }
+    }
+
+    /// Navigates forward in the browser's history
+    pub fn go_forward(&self, browser: HTMLBrowser) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_GoForward(self.html, browser.0) }
+    }
+
+    /// Runs javascript in the context of the current page
+    pub fn execute_javascript(&self, browser: HTMLBrowser, script: &str) {
+        unsafe {
+            let script = CString::new(script).unwrap();
+            sys::SteamAPI_ISteamHTMLSurface_ExecuteJavascript(self.html, browser.0, script.as_ptr())
+        }
+    }
+
+    /// Tells the browser that a mouse button was released
+    pub fn mouse_up(&self, browser: HTMLBrowser, button: HTMLMouseButton) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseUp(self.html, browser.0, button.into()) }
+    }
+
+    /// Tells the browser that a mouse button was pressed
+    pub fn mouse_down(&self, browser: HTMLBrowser, button: HTMLMouseButton) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseDown(self.html, browser.0, button.into()) }
+    }
+
+    /// Tells the browser that a mouse button was double clicked
+    pub fn mouse_double_click(&self, browser: HTMLBrowser, button: HTMLMouseButton) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_MouseDoubleClick(self.html, browser.0, button.into())
+        }
+    }
+
+    /// Tells the browser that the mouse moved to the given position,
+    /// in pixels relative to the top left of the surface
+    pub fn mouse_move(&self, browser: HTMLBrowser, x: i32, y: i32) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseMove(self.html, browser.0, x, y) }
+    }
+
+    /// Tells the browser that the mouse wheel was scrolled
+    pub fn mouse_wheel(&self, browser: HTMLBrowser, delta: i32) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseWheel(self.html, browser.0, delta) }
+    }
+
+    /// Tells the browser that a key was pressed.
+    ///
+    /// `native_key_code` is the virtual key code of the platform. For
+    /// text input `key_char` should be called as well.
+    pub fn key_down(
+        &self,
+        browser: HTMLBrowser,
+        native_key_code: u32,
+        modifiers: HTMLKeyModifiers,
+        is_system_key: bool,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_KeyDown(
+                self.html,
+                browser.0,
+                native_key_code,
+                modifiers.into(),
+                is_system_key,
+            )
+        }
+    }
+
+    /// Tells the browser that a key was released
+    pub fn key_up(&self, browser: HTMLBrowser, native_key_code: u32, modifiers: HTMLKeyModifiers) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_KeyUp(
+                self.html,
+                browser.0,
+                native_key_code,
+                modifiers.into(),
+            )
+        }
+    }
+
+    /// Sends a typed character to the browser
+    pub fn key_char(&self, browser: HTMLBrowser, c: char, modifiers: HTMLKeyModifiers) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_KeyChar(
+                self.html,
+                browser.0,
+                c as u32,
+                modifiers.into(),
+            )
+        }
+    }
+
+    /// Sets whether the browser has keyboard focus
+    pub fn set_key_focus(&self, browser: HTMLBrowser, has_focus: bool) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_SetKeyFocus(self.html, browser.0, has_focus) }
+    }
+
+    /// Allows or denies a navigation requested through an
+    /// `HTMLStartRequest` callback.
+    ///
+    /// This must be called for every `HTMLStartRequest`, otherwise the
+    /// browser stalls.
+    pub fn allow_start_request(&self, browser: HTMLBrowser, allowed: bool) {
+        unsafe { sys::SteamAPI_ISteamHTMLSurface_AllowStartRequest(self.html, browser.0, allowed) }
+    }
+
+    async_call_results! {
+        fn create_browser_async => create_browser(user_agent: Option<&str>, user_css: Option<&str>) -> Result<HTMLBrowser, SteamError>;
+    }
+}
+
+/// A mouse button, as passed to the mouse functions of `HTMLSurface`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HTMLMouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl From<HTMLMouseButton> for sys::ISteamHTMLSurface_EHTMLMouseButton {
+    fn from(button: HTMLMouseButton) -> Self {
+        match button {
+            HTMLMouseButton::Left => sys::ISteamHTMLSurface_EHTMLMouseButton::eHTMLMouseButton_Left,
+            HTMLMouseButton::Right => {
+                sys::ISteamHTMLSurface_EHTMLMouseButton::eHTMLMouseButton_Right
+            }
+            HTMLMouseButton::Middle => {
+                sys::ISteamHTMLSurface_EHTMLMouseButton::eHTMLMouseButton_Middle
+            }
+        }
+    }
+}
+
+bitflags! {
+    /// The modifier keys held down during a key event
+    ///
+    /// `HTMLKeyModifiers::empty()` means no modifier is held.
+    #[repr(C)]
+    pub struct HTMLKeyModifiers: u32 {
+        const ALT   = 0x01;
+        const CTRL  = 0x02;
+        const SHIFT = 0x04;
+    }
+}
+
+impl From<HTMLKeyModifiers> for sys::ISteamHTMLSurface_EHTMLKeyModifiers {
+    fn from(modifiers: HTMLKeyModifiers) -> Self {
+        sys::ISteamHTMLSurface_EHTMLKeyModifiers(modifiers.bits() as _)
+    }
+}
+
+unsafe fn string_from_ptr(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
+
+/// Called when a browser has rendered new content.
+///
+/// `bgra` contains the whole surface in BGRA format, 4 bytes per pixel.
+#[derive(Clone, Debug)]
+pub struct HTMLNeedsPaint {
+    pub browser: HTMLBrowser,
+    /// The pixels of the surface in BGRA format
+    pub bgra: Vec<u8>,
+    /// The width of the surface in pixels
+    pub width: u32,
+    /// The height of the surface in pixels
+    pub height: u32,
+    /// The left edge of the area that changed
+    pub update_x: u32,
+    /// The top edge of the area that changed
+    pub update_y: u32,
+    /// The width of the area that changed
+    pub update_width: u32,
+    /// The height of the area that changed
+    pub update_height: u32,
+    /// The horizontal scroll position of the page
+    pub scroll_x: u32,
+    /// The vertical scroll position of the page
+    pub scroll_y: u32,
+    /// The scale factor of the page
+    pub page_scale: f32,
+    /// Incremented each time a new page is loaded
+    pub page_serial: u32,
+}
+
+unsafe impl Callback for HTMLNeedsPaint {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_NeedsPaint_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_NeedsPaint_t);
+        let len = val.unWide as usize * val.unTall as usize * 4;
+        let bgra = if val.pBGRA.is_null() {
+            Vec::new()
+        } else {
+            std::slice::from_raw_parts(val.pBGRA as *const u8, len).to_vec()
+        };
+        HTMLNeedsPaint {
+            browser: HTMLBrowser(val.unBrowserHandle),
+            bgra,
+            width: val.unWide,
+            height: val.unTall,
+            update_x: val.unUpdateX,
+            update_y: val.unUpdateY,
+            update_width: val.unUpdateWide,
+            update_height: val.unUpdateTall,
+            scroll_x: val.unScrollX,
+            scroll_y: val.unScrollY,
+            page_scale: val.flPageScale,
+            page_serial: val.unPageSerial,
+        }
+    }
+}
+
+/// Called when a browser wants to navigate to a new page.
+///
+/// `HTMLSurface::allow_start_request` must be called in response.
+#[derive(Clone, Debug)]
+pub struct HTMLStartRequest {
+    pub browser: HTMLBrowser,
+    pub url: String,
+    /// The target of the navigation, e.g. `_blank`
+    pub target: String,
+    pub post_data: String,
+    pub is_redirect: bool,
+}
+
+unsafe impl Callback for HTMLStartRequest {
+    const ID: i32 = CALLBACK_BASE_ID + 3;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_StartRequest_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_StartRequest_t);
+        HTMLStartRequest {
+            browser: HTMLBrowser(val.unBrowserHandle),
+            url: string_from_ptr(val.pchURL),
+            target: string_from_ptr(val.pchTarget),
+            post_data: string_from_ptr(val.pchPostData),
+            is_redirect: val.bIsRedirect,
+        }
+    }
+}
+
+/// Called when a browser has been closed, e.g. by `window.close()`
+#[derive(Clone, Debug)]
+pub struct HTMLCloseBrowser {
+    pub browser: HTMLBrowser,
+}
+
+unsafe impl Callback for HTMLCloseBrowser {
+    const ID: i32 = CALLBACK_BASE_ID + 4;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_CloseBrowser_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_CloseBrowser_t);
+        HTMLCloseBrowser {
+            browser: HTMLBrowser(val.unBrowserHandle),
+        }
+    }
+}
+
+/// Called when the URL of a browser changed
+#[derive(Clone, Debug)]
+pub struct HTMLURLChanged {
+    pub browser: HTMLBrowser,
+    pub url: String,
+    pub post_data: String,
+    pub is_redirect: bool,
+    pub page_title: String,
+    /// Whether this is a new navigation rather than a change in history
+    pub new_navigation: bool,
+}
+
+unsafe impl Callback for HTMLURLChanged {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_URLChanged_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_URLChanged_t);
+        HTMLURLChanged {
+            browser: HTMLBrowser(val.unBrowserHandle),
+            url: string_from_ptr(val.pchURL),
+            post_data: string_from_ptr(val.pchPostData),
+            is_redirect: val.bIsRedirect,
+            page_title: string_from_ptr(val.pchPageTitle),
+            new_navigation: val.bNewNavigation,
+        }
+    }
+}
+
+/// Called when a browser has finished loading a page
+#[derive(Clone, Debug)]
+pub struct HTMLFinishedRequest {
+    pub browser: HTMLBrowser,
+    pub url: String,
+    pub page_title: String,
+}
+
+unsafe impl Callback for HTMLFinishedRequest {
+    const ID: i32 = CALLBACK_BASE_ID + 6;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_FinishedRequest_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_FinishedRequest_t);
+        HTMLFinishedRequest {
+            browser: HTMLBrowser(val.unBrowserHandle),
+            url: string_from_ptr(val.pchURL),
+            page_title: string_from_ptr(val.pchPageTitle),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use sys::InputHandle_t;
 
 use super::*;
+use std::time::Duration;
+
+const CALLBACK_BASE_ID: i32 = 2800;
 
 /// Access to the steam input interface
 pub struct Input<Manager> {
@@ -8,6 +11,128 @@ pub struct Input<Manager> {
     pub(crate) _inner: Arc<Inner<Manager>>,
 }
 
+/// The current state of a digital action, as returned by
+/// `Input::get_digital_action_data`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DigitalActionData {
+    /// Whether the action is currently pressed
+    pub state: bool,
+    /// Whether the action is bound and available in the active action
+    /// set
+    pub active: bool,
+}
+
+/// The current state of an analog action, as returned by
+/// `Input::get_analog_action_data`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnalogActionData {
+    /// The current state of the action on the horizontal axis
+    pub x: f32,
+    /// The current state of the action on the vertical axis
+    pub y: f32,
+    /// Whether the action is bound and available in the active action
+    /// set
+    pub active: bool,
+}
+
+/// The size of a glyph returned by `Input::get_glyph_png_for_action_origin`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlyphSize {
+    /// 32x32 pixels
+    Small,
+    /// 128x128 pixels
+    Medium,
+    /// 256x256 pixels
+    Large,
+}
+
+impl From<GlyphSize> for sys::ESteamInputGlyphSize {
+    fn from(size: GlyphSize) -> Self {
+        match size {
+            GlyphSize::Small => sys::ESteamInputGlyphSize::k_ESteamInputGlyphSize_Small,
+            GlyphSize::Medium => sys::ESteamInputGlyphSize::k_ESteamInputGlyphSize_Medium,
+            GlyphSize::Large => sys::ESteamInputGlyphSize::k_ESteamInputGlyphSize_Large,
+        }
+    }
+}
+
+bitflags! {
+    /// The style of a controller glyph
+    ///
+    /// `GlyphStyle::empty()` is the default knockout style: face buttons
+    /// with a white center and black outline.
+    #[repr(C)]
+    pub struct GlyphStyle: u32 {
+        /// Black detail on a white background
+        const LIGHT              = 0x01;
+        /// White detail on a black background
+        const DARK               = 0x02;
+        /// ABXY buttons use the neutral color of the controller
+        const NEUTRAL_COLOR_ABXY = 0x10;
+        /// ABXY buttons use a solid fill
+        const SOLID_ABXY         = 0x20;
+    }
+}
+
+/// The motion data of a controller, as returned by
+/// `Input::get_motion_data`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotionData {
+    /// The orientation of the controller as a quaternion in `[x, y, z, w]`
+    /// order, based on the accelerometer and gyro
+    pub rotation_quaternion: [f32; 4],
+    /// The positional acceleration of the controller
+    pub acceleration: [f32; 3],
+    /// The angular velocity of the controller around each axis
+    pub angular_velocity: [f32; 3],
+}
+
+/// Which side of a controller a haptic event is played on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HapticLocation {
+    Left,
+    Right,
+    Both,
+}
+
+impl From<HapticLocation> for sys::EControllerHapticLocation {
+    fn from(location: HapticLocation) -> Self {
+        match location {
+            HapticLocation::Left => {
+                sys::EControllerHapticLocation::k_EControllerHapticLocation_Left
+            }
+            HapticLocation::Right => {
+                sys::EControllerHapticLocation::k_EControllerHapticLocation_Right
+            }
+            HapticLocation::Both => {
+                sys::EControllerHapticLocation::k_EControllerHapticLocation_Both
+            }
+        }
+    }
+}
+
+/// A trackpad of a Steam Controller or Steam Deck
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ControllerPad {
+    Left,
+    Right,
+}
+
+impl From<ControllerPad> for sys::ESteamControllerPad {
+    fn from(pad: ControllerPad) -> Self {
+        match pad {
+            ControllerPad::Left => sys::ESteamControllerPad::k_ESteamControllerPad_Left,
+            ControllerPad::Right => sys::ESteamControllerPad::k_ESteamControllerPad_Right,
+        }
+    }
+}
+
+/// The type of a connected controller
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InputType {
     Unknown,
     SteamController,
@@ -45,15 +170,10 @@ impl<Manager> Input<Manager> {
 
     /// Returns a list of the currently connected controllers
     pub fn get_connected_controllers(&self) -> Vec<sys::InputHandle_t> {
-        unsafe {
-            let handles = [0_u64; sys::STEAM_INPUT_MAX_COUNT as usize].as_mut_ptr();
-            let quantity = sys::SteamAPI_ISteamInput_GetConnectedControllers(self.input, handles);
-            if quantity == 0 {
-                Vec::new()
-            } else {
-                std::slice::from_raw_parts(handles as *const _, quantity as usize).to_vec()
-            }
-        }
+        let mut handles = vec![0_u64; sys::STEAM_INPUT_MAX_COUNT as usize];
+        let quantity = self.get_connected_controllers_slice(&mut handles);
+        handles.truncate(quantity);
+        handles
     }
 
     /// Returns a list of the currently connected controllers without allocating, and the count
@@ -71,6 +191,15 @@ impl<Manager> Input<Manager> {
         }
     }
 
+    /// Enables the `InputDeviceConnected` and `InputDeviceDisconnected`
+    /// callbacks.
+    ///
+    /// Once enabled, an `InputDeviceConnected` callback is also posted
+    /// for each controller that is already connected.
+    pub fn enable_device_callbacks(&self) {
+        unsafe { sys::SteamAPI_ISteamInput_EnableDeviceCallbacks(self.input) }
+    }
+
     /// Allows to load a specific Action Manifest File localy
     pub fn set_input_action_manifest_file_path(&self, path: &str) -> bool {
         let path = CString::new(path).unwrap();
@@ -85,7 +214,8 @@ impl<Manager> Input<Manager> {
         unsafe { sys::SteamAPI_ISteamInput_GetActionSetHandle(self.input, name.as_ptr()) }
     }
 
-    /// Returns the input type for a controler
+    /// Returns the input type for a controller, e.g. to show the right
+    /// button prompts
     pub fn get_input_type_for_handle(&self, input_handle: sys::InputHandle_t) -> InputType {
         let input_type: sys::ESteamInputType =
             unsafe { sys::SteamAPI_ISteamInput_GetInputTypeForHandle(self.input, input_handle) };
@@ -133,7 +263,46 @@ impl<Manager> Input<Manager> {
         }
     }
 
-    /// Returns the name of an input action
+    /// Returns the path to a PNG glyph for the action origin.
+    ///
+    /// The glyph matches the controller type of the origin and is
+    /// drawn in the requested size and style.
+    pub fn get_glyph_png_for_action_origin(
+        &self,
+        action_origin: sys::EInputActionOrigin,
+        size: GlyphSize,
+        style: GlyphStyle,
+    ) -> String {
+        unsafe {
+            let glyph_path = sys::SteamAPI_ISteamInput_GetGlyphPNGForActionOrigin(
+                self.input,
+                action_origin,
+                size.into(),
+                style.bits(),
+            );
+            let glyph_path = CStr::from_ptr(glyph_path);
+            glyph_path.to_string_lossy().into_owned()
+        }
+    }
+
+    /// Returns the path to an SVG glyph for the action origin.
+    pub fn get_glyph_svg_for_action_origin(
+        &self,
+        action_origin: sys::EInputActionOrigin,
+        style: GlyphStyle,
+    ) -> String {
+        unsafe {
+            let glyph_path = sys::SteamAPI_ISteamInput_GetGlyphSVGForActionOrigin(
+                self.input,
+                action_origin,
+                style.bits(),
+            );
+            let glyph_path = CStr::from_ptr(glyph_path);
+            glyph_path.to_string_lossy().into_owned()
+        }
+    }
+
+    /// Returns the localized name of an action origin, e.g. "A Button"
     pub fn get_string_for_action_origin(&self, action_origin: sys::EInputActionOrigin) -> String {
         unsafe {
             let name_path =
@@ -155,6 +324,72 @@ impl<Manager> Input<Manager> {
         }
     }
 
+    /// Returns the action set that is currently active on the controller
+    pub fn get_current_action_set(
+        &self,
+        input_handle: sys::InputHandle_t,
+    ) -> sys::InputActionSetHandle_t {
+        unsafe { sys::SteamAPI_ISteamInput_GetCurrentActionSet(self.input, input_handle) }
+    }
+
+    /// Activates an action set layer on top of the current action set,
+    /// e.g. for contextual bindings like a vehicle or sniper mode.
+    ///
+    /// Layers are looked up through `get_action_set_handle` like regular
+    /// action sets.
+    pub fn activate_action_set_layer(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_layer_handle: sys::InputActionSetHandle_t,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_ActivateActionSetLayer(
+                self.input,
+                input_handle,
+                action_set_layer_handle,
+            )
+        }
+    }
+
+    /// Deactivates an action set layer activated through
+    /// `activate_action_set_layer`
+    pub fn deactivate_action_set_layer(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_layer_handle: sys::InputActionSetHandle_t,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_DeactivateActionSetLayer(
+                self.input,
+                input_handle,
+                action_set_layer_handle,
+            )
+        }
+    }
+
+    /// Deactivates all action set layers on the controller
+    pub fn deactivate_all_action_set_layers(&self, input_handle: sys::InputHandle_t) {
+        unsafe { sys::SteamAPI_ISteamInput_DeactivateAllActionSetLayers(self.input, input_handle) }
+    }
+
+    /// Returns the action set layers that are currently active on the
+    /// controller
+    pub fn get_active_action_set_layers(
+        &self,
+        input_handle: sys::InputHandle_t,
+    ) -> Vec<sys::InputActionSetHandle_t> {
+        let mut handles = vec![0; sys::STEAM_INPUT_MAX_ACTIVE_LAYERS as usize];
+        let count = unsafe {
+            sys::SteamAPI_ISteamInput_GetActiveActionSetLayers(
+                self.input,
+                input_handle,
+                handles.as_mut_ptr(),
+            )
+        };
+        handles.truncate(count.max(0) as usize);
+        handles
+    }
+
     /// Get the handle of the specified Digital action.
     pub fn get_digital_action_handle(&self, action_name: &str) -> sys::InputDigitalActionHandle_t {
         let name = CString::new(action_name).unwrap();
@@ -172,9 +407,13 @@ impl<Manager> Input<Manager> {
         &self,
         input_handle: sys::InputHandle_t,
         action_handle: sys::InputDigitalActionHandle_t,
-    ) -> sys::InputDigitalActionData_t {
-        unsafe {
+    ) -> DigitalActionData {
+        let data = unsafe {
             sys::SteamAPI_ISteamInput_GetDigitalActionData(self.input, input_handle, action_handle)
+        };
+        DigitalActionData {
+            state: data.bState,
+            active: data.bActive,
         }
     }
 
@@ -183,14 +422,191 @@ impl<Manager> Input<Manager> {
         &self,
         input_handle: sys::InputHandle_t,
         action_handle: sys::InputAnalogActionHandle_t,
-    ) -> sys::InputAnalogActionData_t {
-        unsafe {
+    ) -> AnalogActionData {
+        let data = unsafe {
             sys::SteamAPI_ISteamInput_GetAnalogActionData(self.input, input_handle, action_handle)
+        };
+        AnalogActionData {
+            x: data.x,
+            y: data.y,
+            active: data.bActive,
+        }
+    }
+
+    /// Returns the raw motion data of the controller, if it has a gyro
+    /// and accelerometer.
+    pub fn get_motion_data(&self, input_handle: sys::InputHandle_t) -> MotionData {
+        let data = unsafe { sys::SteamAPI_ISteamInput_GetMotionData(self.input, input_handle) };
+        MotionData {
+            rotation_quaternion: [data.rotQuatX, data.rotQuatY, data.rotQuatZ, data.rotQuatW],
+            acceleration: [data.posAccelX, data.posAccelY, data.posAccelZ],
+            angular_velocity: [data.rotVelX, data.rotVelY, data.rotVelZ],
+        }
+    }
+
+    /// Returns the origins bound to a digital action in the given action
+    /// set, e.g. the buttons that have to be pressed.
+    ///
+    /// These can be passed to `get_glyph_png_for_action_origin` or
+    /// `get_string_for_action_origin` to show button prompts.
+    pub fn get_digital_action_origins(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_handle: sys::InputActionSetHandle_t,
+        action_handle: sys::InputDigitalActionHandle_t,
+    ) -> Vec<sys::EInputActionOrigin> {
+        let mut origins = vec![
+            sys::EInputActionOrigin::k_EInputActionOrigin_None;
+            sys::STEAM_INPUT_MAX_ORIGINS as usize
+        ];
+        let count = unsafe {
+            sys::SteamAPI_ISteamInput_GetDigitalActionOrigins(
+                self.input,
+                input_handle,
+                action_set_handle,
+                action_handle,
+                origins.as_mut_ptr(),
+            )
+        };
+        origins.truncate(count.max(0) as usize);
+        origins
+    }
+
+    /// Returns the origins bound to an analog action in the given action
+    /// set, e.g. the sticks or triggers that control it.
+    pub fn get_analog_action_origins(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_handle: sys::InputActionSetHandle_t,
+        action_handle: sys::InputAnalogActionHandle_t,
+    ) -> Vec<sys::EInputActionOrigin> {
+        let mut origins = vec![
+            sys::EInputActionOrigin::k_EInputActionOrigin_None;
+            sys::STEAM_INPUT_MAX_ORIGINS as usize
+        ];
+        let count = unsafe {
+            sys::SteamAPI_ISteamInput_GetAnalogActionOrigins(
+                self.input,
+                input_handle,
+                action_set_handle,
+                action_handle,
+                origins.as_mut_ptr(),
+            )
+        };
+        origins.truncate(count.max(0) as usize);
+        origins
+    }
+
+    /// Triggers a vibration event on supported controllers.
+    ///
+    /// The speeds of the left and right motors range from 0 (off) to
+    /// `u16::MAX`.
+    pub fn trigger_vibration(
+        &self,
+        input_handle: sys::InputHandle_t,
+        left_speed: u16,
+        right_speed: u16,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_TriggerVibration(
+                self.input,
+                input_handle,
+                left_speed,
+                right_speed,
+            )
         }
     }
 
-    pub fn get_motion_data(&self, input_handle: sys::InputHandle_t) -> sys::InputMotionData_t {
-        unsafe { sys::SteamAPI_ISteamInput_GetMotionData(self.input, input_handle) }
+    /// Triggers a vibration event on supported controllers, including
+    /// the impulse triggers of Xbox One controllers.
+    pub fn trigger_vibration_extended(
+        &self,
+        input_handle: sys::InputHandle_t,
+        left_speed: u16,
+        right_speed: u16,
+        left_trigger_speed: u16,
+        right_trigger_speed: u16,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_TriggerVibrationExtended(
+                self.input,
+                input_handle,
+                left_speed,
+                right_speed,
+                left_trigger_speed,
+                right_trigger_speed,
+            )
+        }
+    }
+
+    /// Sends a haptic event to supported controllers, such as the Steam
+    /// Deck and the Steam Controller.
+    ///
+    /// `intensity` controls the strength of the effect and `gain_db`
+    /// its gain in decibels. The `other_` values are used for the
+    /// opposite side when `location` is `HapticLocation::Both`.
+    pub fn trigger_simple_haptic_event(
+        &self,
+        input_handle: sys::InputHandle_t,
+        location: HapticLocation,
+        intensity: u8,
+        gain_db: i8,
+        other_intensity: u8,
+        other_gain_db: i8,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_TriggerSimpleHapticEvent(
+                self.input,
+                input_handle,
+                location.into(),
+                intensity,
+                gain_db as _,
+                other_intensity,
+                other_gain_db as _,
+            )
+        }
+    }
+
+    /// Triggers a single haptic pulse on a Steam Controller or Steam Deck
+    /// trackpad.
+    pub fn legacy_trigger_haptic_pulse(
+        &self,
+        input_handle: sys::InputHandle_t,
+        pad: ControllerPad,
+        duration: Duration,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_Legacy_TriggerHapticPulse(
+                self.input,
+                input_handle,
+                pad.into(),
+                duration.as_micros().min(u16::MAX as u128) as u16,
+            )
+        }
+    }
+
+    /// Triggers a repeated haptic pulse on a Steam Controller or Steam
+    /// Deck trackpad, alternating between `on` and `off` durations
+    /// `repeat` times.
+    pub fn legacy_trigger_repeated_haptic_pulse(
+        &self,
+        input_handle: sys::InputHandle_t,
+        pad: ControllerPad,
+        on: Duration,
+        off: Duration,
+        repeat: u16,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_Legacy_TriggerRepeatedHapticPulse(
+                self.input,
+                input_handle,
+                pad.into(),
+                on.as_micros().min(u16::MAX as u128) as u16,
+                off.as_micros().min(u16::MAX as u128) as u16,
+                repeat,
+                0,
+            )
+        }
     }
 
     /// Invokes the Steam overlay and brings up the binding screen.
@@ -209,3 +625,45 @@ impl<Manager> Input<Manager> {
         }
     }
 }
+
+/// Called when a new controller has been connected. Requires
+/// `Input::enable_device_callbacks` to be called first.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InputDeviceConnected {
+    /// The handle of the connected controller
+    pub input_handle: sys::InputHandle_t,
+}
+
+unsafe impl Callback for InputDeviceConnected {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamInputDeviceConnected_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamInputDeviceConnected_t);
+        InputDeviceConnected {
+            input_handle: val.m_ulConnectedDeviceHandle,
+        }
+    }
+}
+
+/// Called when a controller has been disconnected. Requires
+/// `Input::enable_device_callbacks` to be called first.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InputDeviceDisconnected {
+    /// The handle of the disconnected controller
+    pub input_handle: sys::InputHandle_t,
+}
+
+unsafe impl Callback for InputDeviceDisconnected {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamInputDeviceDisconnected_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamInputDeviceDisconnected_t);
+        InputDeviceDisconnected {
+            input_handle: val.m_ulDisconnectedDeviceHandle,
+        }
+    }
+}
@@ -1,13 +1,14 @@
 use super::*;
 use crate::sys;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::Instant;
 
 const CALLBACK_BASE_ID: i32 = 1300; // Adjust this base ID as needed for Inventory
 
 pub struct Inventory<Manager> {
     pub(crate) inventory: *mut sys::ISteamInventory,
     pub(crate) _inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
 }
 
 impl<Manager> Inventory<Manager> {
@@ -34,20 +35,33 @@ impl<Manager> Inventory<Manager> {
         &self,
         result_handle: sys::SteamInventoryResult_t,
     ) -> Result<Vec<SteamItemDetails>, InventoryError> {
-        const MAX_ATTEMPTS: u32 = 100;
-        const WAIT_DURATION: Duration = Duration::from_millis(100);
-
-        for _ in 0..MAX_ATTEMPTS {
+        let policy = self._inner.callbacks.lock().unwrap().policy;
+        let options = &self.options;
+        let deadline = Instant::now() + options.timeout.unwrap_or(policy.inventory_timeout);
+
+        loop {
+            if options.token.as_ref().is_some_and(|t| t.is_cancelled()) {
+                self.destroy_result(result_handle);
+                return Err(InventoryError::Cancelled);
+            }
             unsafe {
                 let result =
                     sys::SteamAPI_ISteamInventory_GetResultStatus(self.inventory, result_handle);
-                if result == sys::EResult::k_EResultOK {
-                    return self.get_result_items(result_handle);
+                match result {
+                    sys::EResult::k_EResultOK => return self.get_result_items(result_handle),
+                    sys::EResult::k_EResultPending => {}
+                    err => {
+                        self.destroy_result(result_handle);
+                        return Err(SteamError::from(err).into());
+                    }
                 }
             }
-            std::thread::sleep(WAIT_DURATION);
+            if Instant::now() >= deadline {
+                self.destroy_result(result_handle);
+                return Err(InventoryError::Timeout);
+            }
+            std::thread::sleep(policy.inventory_poll_interval);
         }
-        Err(InventoryError::Timeout)
     }
 
     fn get_result_items(
@@ -151,11 +165,12 @@ impl<Manager> Inventory<Manager> {
             } else {
                 register_call_result::<sys::SteamInventoryStartPurchaseResult_t, _, _>(
                     &self._inner,
+                    &self.options,
                     api_call,
                     CALLBACK_BASE_ID + 1, // Adjust this ID as needed
-                    move |v, io_error| {
-                        cb(if io_error {
-                            Err(SteamError::IOFailure)
+                    move |v, failure| {
+                        cb(if let Some(err) = failure {
+                            Err(err)
                         } else {
                             match v.m_result {
                                 sys::EResult::k_EResultOK => Ok(StartPurchaseResult {
@@ -170,6 +185,10 @@ impl<Manager> Inventory<Manager> {
             }
         }
     }
+
+    async_call_results! {
+        fn start_purchase_async => start_purchase(items: &[(SteamItemDef, u32)]) -> Result<StartPurchaseResult, SteamError>;
+    }
 }
 
 #[derive(Debug, Error)]
@@ -182,10 +201,16 @@ pub enum InventoryError {
     InvalidInput,
     #[error("Timeout waiting for inventory result")]
     Timeout,
+    #[error("The inventory operation was cancelled")]
+    Cancelled,
+    /// Steam returned an error for the inventory result
+    #[error(transparent)]
+    Steam(#[from] SteamError),
 }
 
 /// Represents an individual inventory item with its unique details.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SteamItemDetails {
     pub item_id: SteamItemInstanceID,
     pub definition: SteamItemDef,
@@ -194,6 +219,7 @@ pub struct SteamItemDetails {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SteamItemPrice {
     pub item_def: SteamItemDef,
     pub price: u64,
@@ -202,13 +228,16 @@ pub struct SteamItemPrice {
 
 /// Represents a unique identifier for an inventory item instance.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SteamItemInstanceID(pub u64);
 
 /// Represents a unique identifier for an item definition.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SteamItemDef(pub i32);
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StartPurchaseResult {
     pub order_id: u64,
     pub trans_id: u64,
@@ -16,54 +16,93 @@ use core::ffi::c_void;
 use std::collections::HashMap;
 use std::ffi::{c_char, CStr, CString};
 use std::fmt::{self, Debug, Formatter};
+use std::sync::atomic::AtomicBool;
 use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex, Weak};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Generates `_async` variants of functions that take a call result
+/// callback, returning a `CallResultFuture` instead.
+///
+/// Must be defined before the modules using it.
+macro_rules! async_call_results {
+    ($(fn $name:ident => $base:ident($($arg:ident: $ty:ty),*) -> $out:ty;)*) => {
+        $(
+            #[cfg(feature = "async")]
+            #[doc = concat!("Async version of [`", stringify!($base), "`](#method.", stringify!($base), ").")]
+            pub fn $name(&self, $($arg: $ty),*) -> crate::CallResultFuture<$out> {
+                crate::call_result_future(|c| self.$base($($arg,)* move |v| c.complete(v)))
+            }
+        )*
+    };
+}
+
 pub use crate::app::*;
 pub use crate::callback::*;
 pub use crate::error::*;
 pub use crate::friends::*;
+#[cfg(feature = "async")]
+pub use crate::future::*;
+pub use crate::game_search::*;
+pub use crate::html_surface::*;
 pub use crate::input::*;
 pub use crate::matchmaking::*;
 pub use crate::matchmaking_servers::*;
+pub use crate::music::*;
 pub use crate::networking::*;
+pub use crate::parties::*;
 pub use crate::remote_play::*;
 pub use crate::remote_storage::*;
 pub use crate::server::*;
+pub use crate::server_stats::*;
 pub use crate::timeline::*;
 pub use crate::ugc::*;
 pub use crate::user::*;
 pub use crate::user_stats::*;
 pub use crate::utils::*;
+pub use crate::video::*;
 pub use crate::inventory::*;
 
 mod app;
 mod callback;
 mod error;
 mod friends;
+#[cfg(feature = "async")]
+mod future;
+mod game_search;
+mod html_surface;
 mod input;
 mod matchmaking;
 mod matchmaking_servers;
+#[cfg(feature = "mock")]
+pub mod mock;
+mod music;
 mod networking;
 pub mod networking_messages;
 pub mod networking_sockets;
 mod networking_sockets_callback;
 pub mod networking_types;
 pub mod networking_utils;
+mod parties;
+#[cfg(feature = "raw-bindings")]
+mod raw;
 mod remote_play;
 mod remote_storage;
 pub mod screenshots;
 mod server;
+mod server_stats;
 pub mod timeline;
 mod ugc;
 mod user;
 mod user_stats;
 mod utils;
+mod video;
 mod inventory;
 
+/// The result of a steamworks api call that can fail with any of the
+/// `EResult` codes
 pub type SResult<T> = Result<T, SteamError>;
 
 pub type SIResult<T> = Result<T, SteamAPIInitError>;
@@ -72,12 +111,22 @@ pub type SIResult<T> = Result<T, SteamAPIInitError>;
 // The steam api is assumed to be thread safe unless
 // the documentation for a method states otherwise,
 // however this is never stated anywhere in the docs
-// that I could see.
+// that I could see. The exception is dispatching
+// callbacks which must only happen from one thread
+// at a time, see `CallbackPump`.
 
 /// The main entry point into the steam client.
 ///
 /// This provides access to all of the steamworks api that
 /// clients can use.
+///
+/// # Threading
+///
+/// `Client` is `Send + Sync` and cheap to clone, so clones can be
+/// handed to any thread to make calls from. Callbacks and call results
+/// are only delivered while callbacks are being run, which should be
+/// done from a single thread through the `CallbackPump` returned by
+/// `Client::callback_pump`.
 pub struct Client<Manager = ClientManager> {
     inner: Arc<Inner<Manager>>,
 }
@@ -93,12 +142,23 @@ impl<Manager> Clone for Client<Manager> {
 struct Inner<Manager> {
     _manager: Manager,
     callbacks: Mutex<Callbacks>,
+    /// Whether a `CallbackPump` currently exists for this instance
+    pump_taken: AtomicBool,
     networking_sockets_data: Mutex<NetworkingSocketsData<Manager>>,
 }
 
+type CallbackFn = Box<dyn FnMut(*mut c_void) + Send + 'static>;
+type Subscriber = Arc<Mutex<CallbackFn>>;
+
 struct Callbacks {
-    callbacks: HashMap<i32, Box<dyn FnMut(*mut c_void) + Send + 'static>>,
-    call_results: HashMap<sys::SteamAPICall_t, Box<dyn FnOnce(*mut c_void, bool) + Send + 'static>>,
+    /// Registered callbacks by id, along with the token of the
+    /// `CallbackHandle` that owns them
+    callbacks: HashMap<i32, Vec<(u64, Subscriber)>>,
+    /// The token of the callback kept registered with
+    /// `CallbackHandle::forget` for each id
+    forgotten: HashMap<i32, u64>,
+    call_results: HashMap<sys::SteamAPICall_t, PendingCallResult>,
+    policy: OperationPolicy,
 }
 
 struct NetworkingSocketsData<Manager> {
@@ -119,13 +179,77 @@ unsafe impl<Manager: Send + Sync> Sync for Inner<Manager> {}
 unsafe impl<Manager: Send + Sync> Send for Client<Manager> {}
 unsafe impl<Manager: Send + Sync> Sync for Client<Manager> {}
 
+impl<Manager> Inner<Manager>
+where
+    Manager: crate::Manager,
+{
+    /// Dispatches the pending callbacks of the pipe belonging to `Manager`
+    /// to the callbacks registered on this instance.
+    fn run_callbacks(&self) {
+        unsafe {
+            let pipe = Manager::get_pipe();
+            sys::SteamAPI_ManualDispatch_RunFrame(pipe);
+            let mut callback = std::mem::zeroed();
+            while sys::SteamAPI_ManualDispatch_GetNextCallback(pipe, &mut callback) {
+                if callback.m_iCallback == sys::SteamAPICallCompleted_t_k_iCallback as i32 {
+                    let apicall =
+                        &mut *(callback.m_pubParam as *mut _ as *mut sys::SteamAPICallCompleted_t);
+                    let mut apicall_result = vec![0; apicall.m_cubParam as usize];
+                    let mut failed = false;
+                    let delivered = sys::SteamAPI_ManualDispatch_GetAPICallResult(
+                        pipe,
+                        apicall.m_hAsyncCall,
+                        apicall_result.as_mut_ptr() as *mut _,
+                        apicall.m_cubParam as _,
+                        apicall.m_iCallback,
+                        &mut failed,
+                    );
+                    // The &{val} pattern here is to avoid taking a reference to a packed field
+                    // Since the value here is Copy, we can just copy it and borrow the copy
+                    let pending = self
+                        .callbacks
+                        .lock()
+                        .unwrap()
+                        .call_results
+                        .remove(&{ apicall.m_hAsyncCall });
+                    // Called without the lock held so that the callbacks can start
+                    // new calls
+                    if let Some(cb) = pending {
+                        if delivered && !failed {
+                            (cb.f)(apicall_result.as_mut_ptr() as *mut _, None);
+                        } else {
+                            cb.fail(SteamError::IOFailure);
+                        }
+                    }
+                } else {
+                    self.dispatch_callback(callback.m_iCallback, callback.m_pubParam as *mut _);
+                }
+                sys::SteamAPI_ManualDispatch_FreeLastCallback(pipe);
+            }
+        }
+        self.expire_call_results();
+    }
+}
+
 /// Returns true if the app wasn't launched through steam and
 /// begins relaunching it, the app should exit as soon as possible.
 ///
 /// Returns false if the app was either launched through steam
 /// or has a `steam_appid.txt`
-pub fn restart_app_if_necessary(app_id: AppId) -> bool {
-    unsafe { sys::SteamAPI_RestartAppIfNecessary(app_id.0) }
+///
+/// This should be called before initializing the client.
+///
+/// # Example
+///
+/// ```no_run
+/// if steamworks::restart_app_if_necessary(480) {
+///     // steam is relaunching the game
+///     return;
+/// }
+/// let client = steamworks::Client::init().unwrap();
+/// ```
+pub fn restart_app_if_necessary<ID: Into<AppId>>(app_id: ID) -> bool {
+    unsafe { sys::SteamAPI_RestartAppIfNecessary(app_id.into().0) }
 }
 
 fn static_assert_send<T: Send>() {}
@@ -178,8 +302,11 @@ impl Client<ClientManager> {
                 _manager: ClientManager { _priv: () },
                 callbacks: Mutex::new(Callbacks {
                     callbacks: HashMap::new(),
+                    forgotten: HashMap::new(),
                     call_results: HashMap::new(),
+                    policy: Default::default(),
                 }),
+                pump_taken: AtomicBool::new(false),
                 networking_sockets_data: Mutex::new(NetworkingSocketsData {
                     sockets: Default::default(),
                     independent_connections: Default::default(),
@@ -209,6 +336,53 @@ impl Client<ClientManager> {
         std::env::set_var("SteamGameId", app_id);
         Client::init()
     }
+
+    /// Returns a builder that can be used to configure the client
+    /// before initializing the steamworks api.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// match steamworks::Client::builder().app_id(480).init() {
+    ///     Ok(client) => println!("{}", client.friends().name()),
+    ///     Err(err) => eprintln!("steam failed to start: {}", err.message()),
+    /// }
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder { app_id: None }
+    }
+}
+
+/// Configures and initializes a `Client`.
+///
+/// Created with `Client::builder()`.
+#[derive(Clone, Debug, Default)]
+pub struct ClientBuilder {
+    app_id: Option<AppId>,
+}
+
+impl ClientBuilder {
+    /// Sets the app ID to initialize the api with, instead of using
+    /// the one steam launched the game with or the `steam_appid.txt`
+    pub fn app_id<ID: Into<AppId>>(mut self, app_id: ID) -> Self {
+        self.app_id = Some(app_id.into());
+        self
+    }
+
+    /// Initializes the steamworks api through `SteamAPI_InitFlat` and
+    /// returns a client to access the rest of the api.
+    ///
+    /// # Errors
+    ///
+    /// The returned error contains the message reported by steam
+    /// describing why the api couldn't be initialized. See
+    /// `Client::init` for the common causes.
+    pub fn init(self) -> SIResult<Client<ClientManager>> {
+        match self.app_id {
+            Some(app_id) => Client::init_app(app_id),
+            None => Client::init(),
+        }
+    }
 }
 
 impl<Manager> Client<Manager>
@@ -222,39 +396,66 @@ where
     ///
     /// This should be called frequently (e.g. once per a frame)
     /// in order to reduce the latency between recieving events.
-    pub fn run_callbacks(&self) {
-        unsafe {
-            let pipe = Manager::get_pipe();
-            sys::SteamAPI_ManualDispatch_RunFrame(pipe);
-            let mut callback = std::mem::zeroed();
-            while sys::SteamAPI_ManualDispatch_GetNextCallback(pipe, &mut callback) {
-                let mut callbacks = self.inner.callbacks.lock().unwrap();
-                if callback.m_iCallback == sys::SteamAPICallCompleted_t_k_iCallback as i32 {
-                    let apicall =
-                        &mut *(callback.m_pubParam as *mut _ as *mut sys::SteamAPICallCompleted_t);
-                    let mut apicall_result = vec![0; apicall.m_cubParam as usize];
-                    let mut failed = false;
-                    if sys::SteamAPI_ManualDispatch_GetAPICallResult(
-                        pipe,
-                        apicall.m_hAsyncCall,
-                        apicall_result.as_mut_ptr() as *mut _,
-                        apicall.m_cubParam as _,
-                        apicall.m_iCallback,
-                        &mut failed,
-                    ) {
-                        // The &{val} pattern here is to avoid taking a reference to a packed field
-                        // Since the value here is Copy, we can just copy it and borrow the copy
-                        if let Some(cb) = callbacks.call_results.remove(&{ apicall.m_hAsyncCall }) {
-                            cb(apicall_result.as_mut_ptr() as *mut _, failed);
-                        }
-                    }
-                } else {
-                    if let Some(cb) = callbacks.callbacks.get_mut(&callback.m_iCallback) {
-                        cb(callback.m_pubParam as *mut _);
-                    }
-                }
-                sys::SteamAPI_ManualDispatch_FreeLastCallback(pipe);
-            }
+    ///
+    /// Callbacks are only run from one place at a time, so this does
+    /// nothing and returns `false` while the `CallbackPump` is taken or
+    /// callbacks are already being run on another thread. Returns `true`
+    /// if the callbacks were run.
+    pub fn run_callbacks(&self) -> bool {
+        run_callbacks_through_pump(&self.inner)
+    }
+
+    /// Takes the `CallbackPump` that runs the callbacks of this client.
+    ///
+    /// Only one pump can exist at a time, this returns `None` if the pump
+    /// has already been taken and not been dropped yet.
+    pub fn callback_pump(&self) -> Option<CallbackPump<Manager>> {
+        take_callback_pump(&self.inner)
+    }
+
+    /// Runs the callbacks of this client on a new thread every `interval`,
+    /// for programs that don't have a main loop to run them from.
+    ///
+    /// The thread holds the `CallbackPump` of the client, so this returns
+    /// `None` if the pump has already been taken. The thread is stopped
+    /// when the returned handle is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # let client = steamworks::Client::init().unwrap();
+    /// let _callbacks = client
+    ///     .spawn_callback_thread(Duration::from_millis(50))
+    ///     .unwrap();
+    /// ```
+    pub fn spawn_callback_thread(&self, interval: std::time::Duration) -> Option<CallbackThread>
+    where
+        Manager: Send + Sync + 'static,
+    {
+        spawn_callback_thread(&self.inner, interval)
+    }
+
+    /// Returns the timeouts currently used for pending operations
+    pub fn operation_policy(&self) -> OperationPolicy {
+        self.inner.callbacks.lock().unwrap().policy
+    }
+
+    /// Sets the timeouts used for pending operations started after this
+    /// call. The interfaces can override them with `with_call_options`.
+    pub fn set_operation_policy(&self, policy: OperationPolicy) {
+        self.inner.callbacks.lock().unwrap().policy = policy;
+    }
+
+    /// Fails all pending call results with `SteamError::Cancelled`.
+    ///
+    /// Pending call results keep everything captured by their callbacks
+    /// alive, which can include clients. Calling this before shutting
+    /// down makes sure the clients are released.
+    pub fn cancel_pending_call_results(&self) {
+        let pending = std::mem::take(&mut self.inner.callbacks.lock().unwrap().call_results);
+        for (_, pending) in pending {
+            pending.fail(SteamError::Cancelled);
         }
     }
 
@@ -263,6 +464,10 @@ where
     ///
     /// The callback will be run on the thread that `run_callbacks`
     /// is called when the event arrives.
+    ///
+    /// The callback stays registered until the returned handle is dropped.
+    /// Any number of callbacks can be registered for the same type, they
+    /// are run in the order they were registered.
     pub fn register_callback<C, F>(&self, f: F) -> CallbackHandle<Manager>
     where
         C: Callback,
@@ -271,6 +476,43 @@ where
         unsafe { register_callback(&self.inner, f) }
     }
 
+    /// Registers a function to be called with whether the steam overlay
+    /// is now active, whenever it is opened or closed.
+    ///
+    /// This is a shortcut for registering a `GameOverlayActivated`
+    /// callback, e.g. to pause a single player game while the overlay is
+    /// open. The same rules as for `register_callback` apply.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let client = steamworks::Client::init().unwrap();
+    /// let _overlay = client.on_overlay_toggled(|active| {
+    ///     if active {
+    ///         println!("pausing");
+    ///     } else {
+    ///         println!("resuming");
+    ///     }
+    /// });
+    /// ```
+    pub fn on_overlay_toggled<F>(&self, mut f: F) -> CallbackHandle<Manager>
+    where
+        F: FnMut(bool) + 'static + Send,
+    {
+        self.register_callback(move |v: GameOverlayActivated| f(v.active))
+    }
+
+    /// Returns a stream of the callback events of the given type.
+    ///
+    /// The events are buffered when `run_callbacks` is called and can be
+    /// taken out of the stream at any later point.
+    pub fn callback_stream<C>(&self) -> CallbackStream<C, Manager>
+    where
+        C: Callback + Send + 'static,
+    {
+        unsafe { register_callback_stream(&self.inner) }
+    }
+
     /// Returns an accessor to the steam utils interface
     pub fn utils(&self) -> Utils<Manager> {
         unsafe {
@@ -291,6 +533,7 @@ where
             Matchmaking {
                 mm: mm,
                 inner: self.inner.clone(),
+                options: CallOptions::default(),
             }
         }
     }
@@ -326,7 +569,8 @@ where
             debug_assert!(!apps.is_null());
             Apps {
                 apps: apps,
-                _inner: self.inner.clone(),
+                inner: self.inner.clone(),
+                options: CallOptions::default(),
             }
         }
     }
@@ -362,7 +606,8 @@ where
             debug_assert!(!user.is_null());
             User {
                 user,
-                _inner: self.inner.clone(),
+                inner: self.inner.clone(),
+                options: CallOptions::default(),
             }
         }
     }
@@ -375,6 +620,20 @@ where
             UserStats {
                 user_stats: us,
                 inner: self.inner.clone(),
+                options: CallOptions::default(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam parties interface
+    pub fn parties(&self) -> Parties<Manager> {
+        unsafe {
+            let parties = sys::SteamAPI_SteamParties_v002();
+            debug_assert!(!parties.is_null());
+            Parties {
+                parties,
+                inner: self.inner.clone(),
+                options: CallOptions::default(),
             }
         }
     }
@@ -406,6 +665,43 @@ where
         }
     }
 
+    /// Returns an accessor to the steam game search interface
+    pub fn game_search(&self) -> GameSearch<Manager> {
+        unsafe {
+            let search = sys::SteamAPI_SteamGameSearch_v001();
+            debug_assert!(!search.is_null());
+            GameSearch {
+                search,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam HTML surface interface
+    pub fn html_surface(&self) -> HTMLSurface<Manager> {
+        unsafe {
+            let html = sys::SteamAPI_SteamHTMLSurface_v005();
+            debug_assert!(!html.is_null());
+            HTMLSurface {
+                html,
+                inner: self.inner.clone(),
+                options: CallOptions::default(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam music interface
+    pub fn music(&self) -> Music<Manager> {
+        unsafe {
+            let music = sys::SteamAPI_SteamMusic_v001();
+            debug_assert!(!music.is_null());
+            Music {
+                music,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
     /// Returns an accessor to the steam screenshots interface
     pub fn screenshots(&self) -> Screenshots<Manager> {
         unsafe {
@@ -426,6 +722,7 @@ where
             UGC {
                 ugc,
                 inner: self.inner.clone(),
+                options: CallOptions::default(),
             }
         }
     }
@@ -443,6 +740,18 @@ where
         }
     }
 
+    /// Returns an accessor to the steam video interface
+    pub fn video(&self) -> Video<Manager> {
+        unsafe {
+            let video = sys::SteamAPI_SteamVideo_v007();
+            debug_assert!(!video.is_null());
+            Video {
+                video,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
     /// Returns an accessor to the steam inventory interface
     pub fn inventory(&self) -> Inventory<Manager> {
         unsafe {
@@ -451,6 +760,7 @@ where
             Inventory {
                 inventory: inventory,
                 _inner: self.inner.clone(),
+                options: CallOptions::default(),
             }
         }
     }
@@ -551,6 +861,239 @@ impl SteamId {
         let last_bit = account_id & 1;
         format!("STEAM_0:{}:{}", last_bit, (account_id >> 1))
     }
+
+    /// Creates a `SteamId` from its components
+    pub fn from_parts(
+        account_id: AccountId,
+        instance: u32,
+        account_type: AccountType,
+        universe: Universe,
+    ) -> SteamId {
+        SteamId(
+            u64::from(account_id.0)
+                | (u64::from(instance & 0xF_FF_FF) << 32)
+                | ((account_type as u64) << 52)
+                | ((universe as u64) << 56),
+        )
+    }
+
+    /// Returns the instance of the account, for user accounts this is
+    /// usually `1` (desktop)
+    pub fn account_instance(&self) -> u32 {
+        ((self.0 >> 32) & 0xF_FF_FF) as u32
+    }
+
+    /// Returns the type of account this steam id belongs to
+    pub fn account_type(&self) -> AccountType {
+        AccountType::from_raw(((self.0 >> 52) & 0xF) as u8)
+    }
+
+    /// Returns the universe this steam id belongs to
+    pub fn universe(&self) -> Universe {
+        Universe::from_raw((self.0 >> 56) as u8)
+    }
+
+    /// Returns the formatted SteamID3 string for this steam id,
+    /// e.g. `[U:1:80628317]`
+    pub fn steamid3(&self) -> String {
+        let account_type = self.account_type();
+        let instance = self.account_instance();
+        let letter = match account_type {
+            AccountType::Chat if instance & CHAT_INSTANCE_FLAG_CLAN != 0 => 'c',
+            AccountType::Chat if instance & CHAT_INSTANCE_FLAG_LOBBY != 0 => 'L',
+            ty => ty.steamid3_letter(),
+        };
+        let universe = self.universe() as u8;
+        let account_id = self.account_id().raw();
+        match account_type {
+            AccountType::AnonGameServer => {
+                format!("[{}:{}:{}:{}]", letter, universe, account_id, instance)
+            }
+            AccountType::Individual if instance != 1 => {
+                format!("[{}:{}:{}:{}]", letter, universe, account_id, instance)
+            }
+            _ => format!("[{}:{}:{}]", letter, universe, account_id),
+        }
+    }
+
+    fn from_steamid32(s: &str) -> Option<SteamId> {
+        let mut parts = s.strip_prefix("STEAM_")?.split(':');
+        let universe: u8 = parts.next()?.parse().ok()?;
+        let low_bit: u32 = parts.next()?.parse().ok()?;
+        let high_bits: u32 = parts.next()?.parse().ok()?;
+        if parts.next().is_some() || low_bit > 1 || high_bits > u32::MAX >> 1 {
+            return None;
+        }
+        // Old formats used 0 for the public universe
+        let universe = match universe {
+            0 => Universe::Public,
+            u => Universe::from_known(u)?,
+        };
+        Some(SteamId::from_parts(
+            AccountId(high_bits << 1 | low_bit),
+            1,
+            AccountType::Individual,
+            universe,
+        ))
+    }
+
+    fn from_steamid3(s: &str) -> Option<SteamId> {
+        let mut parts = s.strip_prefix('[')?.strip_suffix(']')?.split(':');
+        let letter = parts.next()?;
+        let universe = Universe::from_known(parts.next()?.parse().ok()?)?;
+        let account_id = AccountId(parts.next()?.parse().ok()?);
+        let instance: Option<u32> = match parts.next() {
+            Some(instance) => Some(instance.parse().ok()?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        let (account_type, default_instance) = match letter {
+            "c" => (AccountType::Chat, CHAT_INSTANCE_FLAG_CLAN),
+            "L" => (AccountType::Chat, CHAT_INSTANCE_FLAG_LOBBY),
+            "U" => (AccountType::Individual, 1),
+            letter => (
+                AccountType::ALL
+                    .into_iter()
+                    .find(|ty| letter.len() == 1 && letter.starts_with(ty.steamid3_letter()))?,
+                0,
+            ),
+        };
+        Some(SteamId::from_parts(
+            account_id,
+            instance.unwrap_or(default_instance),
+            account_type,
+            universe,
+        ))
+    }
+}
+
+const CHAT_INSTANCE_FLAG_CLAN: u32 = 0x8_00_00;
+const CHAT_INSTANCE_FLAG_LOBBY: u32 = 0x4_00_00;
+
+impl From<u64> for SteamId {
+    fn from(id: u64) -> Self {
+        SteamId(id)
+    }
+}
+
+impl From<SteamId> for u64 {
+    fn from(id: SteamId) -> Self {
+        id.0
+    }
+}
+
+impl std::str::FromStr for SteamId {
+    type Err = ParseSteamIdError;
+
+    /// Parses a steam id from either its raw 64 bit value
+    /// (`76561198040894045`), the SteamID32 format (`STEAM_0:1:40314158`)
+    /// or the SteamID3 format (`[U:1:80628317]`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(id) = s.parse::<u64>() {
+            return Ok(SteamId(id));
+        }
+        SteamId::from_steamid32(s)
+            .or_else(|| SteamId::from_steamid3(s))
+            .ok_or(ParseSteamIdError)
+    }
+}
+
+impl TryFrom<&str> for SteamId {
+    type Error = ParseSteamIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// The steam universe a steam id belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum Universe {
+    Invalid = 0,
+    Public = 1,
+    Beta = 2,
+    Internal = 3,
+    Dev = 4,
+}
+
+impl Universe {
+    fn from_raw(raw: u8) -> Universe {
+        Universe::from_known(raw).unwrap_or(Universe::Invalid)
+    }
+
+    /// Returns `None` for values that don't name a universe
+    fn from_known(raw: u8) -> Option<Universe> {
+        Some(match raw {
+            0 => Universe::Invalid,
+            1 => Universe::Public,
+            2 => Universe::Beta,
+            3 => Universe::Internal,
+            4 => Universe::Dev,
+            _ => return None,
+        })
+    }
+}
+
+/// The type of account a steam id belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum AccountType {
+    Invalid = 0,
+    Individual = 1,
+    Multiseat = 2,
+    GameServer = 3,
+    AnonGameServer = 4,
+    Pending = 5,
+    ContentServer = 6,
+    Clan = 7,
+    Chat = 8,
+    ConsoleUser = 9,
+    AnonUser = 10,
+}
+
+impl AccountType {
+    const ALL: [AccountType; 11] = [
+        AccountType::Invalid,
+        AccountType::Individual,
+        AccountType::Multiseat,
+        AccountType::GameServer,
+        AccountType::AnonGameServer,
+        AccountType::Pending,
+        AccountType::ContentServer,
+        AccountType::Clan,
+        AccountType::Chat,
+        AccountType::ConsoleUser,
+        AccountType::AnonUser,
+    ];
+
+    fn from_raw(raw: u8) -> AccountType {
+        AccountType::ALL
+            .get(raw as usize)
+            .copied()
+            .unwrap_or(AccountType::Invalid)
+    }
+
+    fn steamid3_letter(self) -> char {
+        match self {
+            AccountType::Invalid => 'I',
+            AccountType::Individual => 'U',
+            AccountType::Multiseat => 'M',
+            AccountType::GameServer => 'G',
+            AccountType::AnonGameServer => 'A',
+            AccountType::Pending => 'P',
+            AccountType::ContentServer => 'C',
+            AccountType::Clan => 'g',
+            AccountType::Chat => 'T',
+            AccountType::ConsoleUser => 'I',
+            AccountType::AnonUser => 'a',
+        }
+    }
 }
 
 /// A user's account id
@@ -607,6 +1150,24 @@ impl GameId {
     }
 }
 
+impl From<AppId> for GameId {
+    fn from(app_id: AppId) -> Self {
+        GameId(u64::from(app_id.0))
+    }
+}
+
+impl From<u64> for GameId {
+    fn from(id: u64) -> Self {
+        GameId(id)
+    }
+}
+
+impl From<GameId> for u64 {
+    fn from(id: GameId) -> Self {
+        id.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serial_test::serial;
@@ -661,4 +1222,86 @@ mod tests {
         let steamid = SteamId(76561198174976054);
         assert_eq!("STEAM_0:0:107355163", steamid.steamid32());
     }
+
+    #[test]
+    fn steamid_parts_test() {
+        let steamid = SteamId(76561198040894045);
+        assert_eq!(steamid.universe(), Universe::Public);
+        assert_eq!(steamid.account_type(), AccountType::Individual);
+        assert_eq!(steamid.account_instance(), 1);
+        assert_eq!(
+            SteamId::from_parts(
+                steamid.account_id(),
+                1,
+                AccountType::Individual,
+                Universe::Public
+            ),
+            steamid
+        );
+    }
+
+    #[test]
+    fn steamid_format_test() {
+        let steamid = SteamId(76561198040894045);
+        assert_eq!("[U:1:80628317]", steamid.steamid3());
+
+        let lobby = SteamId::from_parts(
+            AccountId(12345),
+            CHAT_INSTANCE_FLAG_LOBBY,
+            AccountType::Chat,
+            Universe::Public,
+        );
+        assert_eq!("[L:1:12345]", lobby.steamid3());
+
+        let clan = SteamId::from_parts(AccountId(4), 0, AccountType::Clan, Universe::Public);
+        assert_eq!("[g:1:4]", clan.steamid3());
+    }
+
+    #[test]
+    fn steamid_parse_test() {
+        let steamid = SteamId(76561198040894045);
+        assert_eq!("76561198040894045".parse(), Ok(steamid));
+        assert_eq!("STEAM_0:1:40314158".parse(), Ok(steamid));
+        assert_eq!("STEAM_1:1:40314158".parse(), Ok(steamid));
+        assert_eq!("[U:1:80628317]".parse(), Ok(steamid));
+        assert_eq!(SteamId::try_from(" [U:1:80628317] "), Ok(steamid));
+
+        for id in [
+            SteamId(76561198174976054),
+            SteamId::from_parts(
+                AccountId(12345),
+                0,
+                AccountType::GameServer,
+                Universe::Public,
+            ),
+            SteamId::from_parts(
+                AccountId(7),
+                1234,
+                AccountType::AnonGameServer,
+                Universe::Beta,
+            ),
+            SteamId::from_parts(
+                AccountId(12345),
+                CHAT_INSTANCE_FLAG_CLAN,
+                AccountType::Chat,
+                Universe::Public,
+            ),
+        ] {
+            assert_eq!(id.steamid3().parse(), Ok(id));
+        }
+
+        assert_eq!("".parse::<SteamId>(), Err(ParseSteamIdError));
+        assert_eq!("STEAM_0:2:1".parse::<SteamId>(), Err(ParseSteamIdError));
+        assert_eq!("[U:1]".parse::<SteamId>(), Err(ParseSteamIdError));
+        assert_eq!("[X:1:5]".parse::<SteamId>(), Err(ParseSteamIdError));
+        assert_eq!("[U:9:5]".parse::<SteamId>(), Err(ParseSteamIdError));
+        assert_eq!("STEAM_7:1:1".parse::<SteamId>(), Err(ParseSteamIdError));
+    }
+
+    #[test]
+    fn gameid_test() {
+        let game = GameId::from(AppId(480));
+        assert_eq!(game.app_id(), AppId(480));
+        assert_eq!(u64::from(game), 480);
+    }
 }
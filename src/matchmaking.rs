@@ -8,6 +8,7 @@ use serial_test::serial;
 pub struct Matchmaking<Manager> {
     pub(crate) mm: *mut sys::ISteamMatchmaking,
     pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
 }
 
 const CALLBACK_BASE_ID: i32 = 500;
@@ -53,11 +54,12 @@ impl<Manager> Matchmaking<Manager> {
             let api_call = sys::SteamAPI_ISteamMatchmaking_RequestLobbyList(self.mm);
             register_call_result::<sys::LobbyMatchList_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 10,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else {
                         let mut out = Vec::with_capacity(v.m_nLobbiesMatching as usize);
                         for idx in 0..v.m_nLobbiesMatching {
@@ -99,11 +101,12 @@ impl<Manager> Matchmaking<Manager> {
                 sys::SteamAPI_ISteamMatchmaking_CreateLobby(self.mm, ty, max_members as _);
             register_call_result::<sys::LobbyCreated_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 13,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else if v.m_eResult != sys::EResult::k_EResultOK {
                         Err(v.m_eResult.into())
                     } else {
@@ -123,10 +126,11 @@ impl<Manager> Matchmaking<Manager> {
             let api_call = sys::SteamAPI_ISteamMatchmaking_JoinLobby(self.mm, lobby.0);
             register_call_result::<sys::LobbyEnter_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 4,
-                move |v, io_error| {
-                    cb(if io_error || v.m_EChatRoomEnterResponse != 1 {
+                move |v, failure| {
+                    cb(if failure.is_some() || v.m_EChatRoomEnterResponse != 1 {
                         Err(())
                     } else {
                         Ok(LobbyId(v.m_ulSteamIDLobby))
@@ -533,6 +537,12 @@ impl<Manager> Matchmaking<Manager> {
         }
         self
     }
+
+    async_call_results! {
+        fn request_lobby_list_async => request_lobby_list() -> SResult<Vec<LobbyId>>;
+        fn create_lobby_async => create_lobby(ty: LobbyType, max_members: u32) -> SResult<LobbyId>;
+        fn join_lobby_async => join_lobby(lobby: LobbyId) -> Result<LobbyId, ()>;
+    }
 }
 
 /// Filters for the lobbies to be returned from `request_lobby_list`.
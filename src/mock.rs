@@ -0,0 +1,828 @@
+//! An in-memory stand-in for the steam client.
+//!
+//! Enabled with the `mock` feature. `MockClient` mirrors the parts of the
+//! `Client` api that games commonly depend on (stats and achievements,
+//! cloud files, lobbies and inventory) but keeps all of its state in
+//! memory, so code using it can be tested on machines without steam
+//! installed.
+//!
+//! The mock is a lookalike rather than a drop-in replacement: its
+//! accessors return their own types, but the methods they have in common
+//! with the real interfaces take and return exactly the same types. Code
+//! written against the real interfaces compiles against the mock by
+//! swapping the types, e.g. with a `#[cfg(test)]` type alias, as long as
+//! it only uses the methods the mock implements. The `define_*`,
+//! `add_lobby_member` and `grant_item` methods only exist on the mock to
+//! set up its state.
+//!
+//! Like the real client, the results of asynchronous calls are only
+//! delivered once `MockClient::run_callbacks` is called.
+//!
+//! # Example
+//!
+//! ```
+//! use steamworks::mock::MockClient;
+//!
+//! let client = MockClient::new();
+//! client.user_stats().define_achievement("ACH_WIN_ONE_GAME");
+//!
+//! let stats = client.user_stats();
+//! stats.achievement("ACH_WIN_ONE_GAME").set().unwrap();
+//! assert_eq!(stats.achievement("ACH_WIN_ONE_GAME").get(), Ok(true));
+//! ```
+
+use super::*;
+
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read, Write};
+
+type PendingCallback = Box<dyn FnOnce() + Send + 'static>;
+
+/// The steam id of the user that a `MockClient` is logged in as,
+/// unless another one is passed to `MockClient::with_steam_id`
+pub const MOCK_STEAM_ID: SteamId = SteamId(76561197960287930);
+
+const MOCK_LOBBY_ID_BASE: u64 = 109775240917171200;
+
+/// An in-memory fake of the steam client.
+///
+/// Clones share the same state.
+#[derive(Clone)]
+pub struct MockClient {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    steam_id: u64,
+    pending: Vec<PendingCallback>,
+    stats_i32: HashMap<String, i32>,
+    stats_f32: HashMap<String, f32>,
+    achievements: BTreeMap<String, bool>,
+    cloud_enabled: bool,
+    files: BTreeMap<String, MockFileData>,
+    next_lobby: u64,
+    lobbies: HashMap<u64, MockLobby>,
+    next_item: u64,
+    items: BTreeMap<u64, SteamItemDetails>,
+}
+
+struct MockFileData {
+    data: Vec<u8>,
+    timestamp: i64,
+}
+
+struct MockLobby {
+    ty: LobbyType,
+    max_members: u32,
+    owner: u64,
+    members: Vec<u64>,
+    joinable: bool,
+    data: BTreeMap<String, Arc<str>>,
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        MockClient::new()
+    }
+}
+
+impl MockClient {
+    /// Creates a new client logged in as `MOCK_STEAM_ID`
+    pub fn new() -> MockClient {
+        MockClient::with_steam_id(MOCK_STEAM_ID)
+    }
+
+    /// Creates a new client logged in as the given user
+    pub fn with_steam_id(steam_id: SteamId) -> MockClient {
+        MockClient {
+            state: Arc::new(Mutex::new(MockState {
+                steam_id: steam_id.0,
+                cloud_enabled: true,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Returns the steam id of the user the client is logged in as
+    pub fn steam_id(&self) -> SteamId {
+        SteamId(self.lock().steam_id)
+    }
+
+    /// Runs any currently pending callbacks
+    ///
+    /// Always returns `true`, as the mock has no callback pump that could
+    /// be taken.
+    pub fn run_callbacks(&self) -> bool {
+        let pending = std::mem::take(&mut self.lock().pending);
+        for cb in pending {
+            cb();
+        }
+        true
+    }
+
+    /// Returns an accessor to the fake stats interface
+    pub fn user_stats(&self) -> MockUserStats {
+        MockUserStats {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns an accessor to the fake cloud storage interface
+    pub fn remote_storage(&self) -> MockRemoteStorage {
+        MockRemoteStorage {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns an accessor to the fake matchmaking interface
+    pub fn matchmaking(&self) -> MockMatchmaking {
+        MockMatchmaking {
+            client: self.clone(),
+            strings: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns an accessor to the fake inventory interface
+    pub fn inventory(&self) -> MockInventory {
+        MockInventory {
+            client: self.clone(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap()
+    }
+
+    fn queue<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.lock().pending.push(Box::new(f));
+    }
+}
+
+/// The fake counterpart of `UserStats`.
+///
+/// Stats and achievements have to be defined before they can be used,
+/// just like they have to be set up for the app on steamworks.
+pub struct MockUserStats {
+    client: MockClient,
+}
+
+// The `Result<_, ()>` return types mirror the ones of `UserStats`
+#[allow(clippy::result_unit_err)]
+impl MockUserStats {
+    /// Defines an integer stat with the given initial value
+    pub fn define_stat_i32(&self, name: &str, value: i32) {
+        self.client.lock().stats_i32.insert(name.to_owned(), value);
+    }
+
+    /// Defines a float stat with the given initial value
+    pub fn define_stat_f32(&self, name: &str, value: f32) {
+        self.client.lock().stats_f32.insert(name.to_owned(), value);
+    }
+
+    /// Defines a locked achievement
+    pub fn define_achievement(&self, name: &str) {
+        self.client
+            .lock()
+            .achievements
+            .insert(name.to_owned(), false);
+    }
+
+    /// Does nothing, the stats of the mock client are always available
+    pub fn request_current_stats(&self) {}
+
+    /// Always succeeds, the stats of the mock client are only kept in memory
+    pub fn store_stats(&self) -> Result<(), ()> {
+        Ok(())
+    }
+
+    /// Resets all stats to zero and optionally locks all achievements
+    pub fn reset_all_stats(&self, achievements_too: bool) -> Result<(), ()> {
+        let mut state = self.client.lock();
+        state.stats_i32.values_mut().for_each(|v| *v = 0);
+        state.stats_f32.values_mut().for_each(|v| *v = 0.0);
+        if achievements_too {
+            state.achievements.values_mut().for_each(|v| *v = false);
+        }
+        Ok(())
+    }
+
+    /// Returns the value of an integer stat, or `Err` if it isn't defined
+    pub fn get_stat_i32(&self, name: &str) -> Result<i32, ()> {
+        self.client.lock().stats_i32.get(name).copied().ok_or(())
+    }
+
+    /// Sets the value of an integer stat, or returns `Err` if it isn't defined
+    pub fn set_stat_i32(&self, name: &str, stat: i32) -> Result<(), ()> {
+        match self.client.lock().stats_i32.get_mut(name) {
+            Some(v) => {
+                *v = stat;
+                Ok(())
+            }
+            None => Err(()),
+        }
+    }
+
+    /// Returns the value of a float stat, or `Err` if it isn't defined
+    pub fn get_stat_f32(&self, name: &str) -> Result<f32, ()> {
+        self.client.lock().stats_f32.get(name).copied().ok_or(())
+    }
+
+    /// Sets the value of a float stat, or returns `Err` if it isn't defined
+    pub fn set_stat_f32(&self, name: &str, stat: f32) -> Result<(), ()> {
+        match self.client.lock().stats_f32.get_mut(name) {
+            Some(v) => {
+                *v = stat;
+                Ok(())
+            }
+            None => Err(()),
+        }
+    }
+
+    /// Access achievement information.
+    pub fn achievement(&self, name: &str) -> MockAchievement {
+        MockAchievement {
+            name: name.to_owned(),
+            client: self.client.clone(),
+        }
+    }
+
+    /// Returns the number of defined achievements
+    pub fn get_num_achievements(&self) -> Result<u32, ()> {
+        Ok(self.client.lock().achievements.len() as u32)
+    }
+
+    /// Returns the names of all defined achievements
+    pub fn get_achievement_names(&self) -> Option<Vec<String>> {
+        Some(self.client.lock().achievements.keys().cloned().collect())
+    }
+}
+
+/// The fake counterpart of `AchievementHelper`
+pub struct MockAchievement {
+    name: String,
+    client: MockClient,
+}
+
+#[allow(clippy::result_unit_err)]
+impl MockAchievement {
+    /// Returns whether the achievement is unlocked, or `Err` if it isn't defined
+    pub fn get(&self) -> Result<bool, ()> {
+        self.client
+            .lock()
+            .achievements
+            .get(&self.name)
+            .copied()
+            .ok_or(())
+    }
+
+    /// Unlocks the achievement, or returns `Err` if it isn't defined
+    pub fn set(&self) -> Result<(), ()> {
+        self.set_achieved(true)
+    }
+
+    /// Locks the achievement, or returns `Err` if it isn't defined
+    pub fn clear(&self) -> Result<(), ()> {
+        self.set_achieved(false)
+    }
+
+    fn set_achieved(&self, achieved: bool) -> Result<(), ()> {
+        match self.client.lock().achievements.get_mut(&self.name) {
+            Some(v) => {
+                *v = achieved;
+                Ok(())
+            }
+            None => Err(()),
+        }
+    }
+}
+
+/// The fake counterpart of `RemoteStorage`
+pub struct MockRemoteStorage {
+    client: MockClient,
+}
+
+impl MockRemoteStorage {
+    /// Toggles whether the steam cloud is enabled for the application
+    pub fn set_cloud_enabled_for_app(&self, enabled: bool) {
+        self.client.lock().cloud_enabled = enabled;
+    }
+
+    /// Returns whether the steam cloud is enabled for the application
+    pub fn is_cloud_enabled_for_app(&self) -> bool {
+        self.client.lock().cloud_enabled
+    }
+
+    /// Always returns true
+    pub fn is_cloud_enabled_for_account(&self) -> bool {
+        true
+    }
+
+    /// Returns information about all files in the fake cloud storage
+    pub fn files(&self) -> Vec<SteamFileInfo> {
+        self.client
+            .lock()
+            .files
+            .iter()
+            .map(|(name, file)| SteamFileInfo {
+                name: name.clone(),
+                size: file.data.len() as u64,
+            })
+            .collect()
+    }
+
+    /// Returns a handle to a fake cloud file
+    ///
+    /// The file does not have to exist.
+    pub fn file(&self, name: &str) -> MockFile {
+        MockFile {
+            name: name.to_owned(),
+            client: self.client.clone(),
+        }
+    }
+}
+
+/// The fake counterpart of `SteamFile`
+pub struct MockFile {
+    name: String,
+    client: MockClient,
+}
+
+impl MockFile {
+    /// Deletes the file
+    pub fn delete(&self) -> bool {
+        self.client.lock().files.remove(&self.name).is_some()
+    }
+
+    /// Deletes the file, there is no local copy to keep
+    pub fn forget(&self) -> bool {
+        self.delete()
+    }
+
+    /// Returns whether a file with the name exists
+    pub fn exists(&self) -> bool {
+        self.client.lock().files.contains_key(&self.name)
+    }
+
+    /// Returns whether a file with the name exists
+    pub fn is_persisted(&self) -> bool {
+        self.exists()
+    }
+
+    /// Returns the number of times the file has been written, or 0 if
+    /// it doesn't exist
+    pub fn timestamp(&self) -> i64 {
+        self.client
+            .lock()
+            .files
+            .get(&self.name)
+            .map_or(0, |f| f.timestamp)
+    }
+
+    /// Returns a handle that replaces the contents of the file once dropped
+    pub fn write(self) -> MockFileWriter {
+        MockFileWriter {
+            file: self,
+            data: Vec::new(),
+        }
+    }
+
+    /// Returns a handle that reads the current contents of the file
+    pub fn read(self) -> MockFileReader {
+        let data = self
+            .client
+            .lock()
+            .files
+            .get(&self.name)
+            .map(|f| f.data.clone())
+            .unwrap_or_default();
+        MockFileReader {
+            data: Cursor::new(data),
+        }
+    }
+}
+
+/// A write handle for a fake cloud file
+pub struct MockFileWriter {
+    file: MockFile,
+    data: Vec<u8>,
+}
+
+impl Write for MockFileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.data.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for MockFileWriter {
+    fn drop(&mut self) {
+        let mut state = self.file.client.lock();
+        let timestamp = state
+            .files
+            .get(&self.file.name)
+            .map_or(1, |f| f.timestamp + 1);
+        state.files.insert(
+            self.file.name.clone(),
+            MockFileData {
+                data: std::mem::take(&mut self.data),
+                timestamp,
+            },
+        );
+    }
+}
+
+/// A read handle for a fake cloud file
+pub struct MockFileReader {
+    data: Cursor<Vec<u8>>,
+}
+
+impl Read for MockFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.data.read(buf)
+    }
+}
+
+/// The fake counterpart of `Matchmaking`.
+///
+/// Lobbies only exist within the `MockClient` and its clones. Lobby list
+/// filters are not supported, `request_lobby_list` returns all public
+/// joinable lobbies.
+pub struct MockMatchmaking {
+    client: MockClient,
+    /// Keeps the strings returned by `lobby_data` alive for as long as
+    /// they are borrowed from this accessor, keyed by their address so
+    /// reading the same value again doesn't store it twice
+    strings: Mutex<HashMap<usize, Arc<str>>>,
+}
+
+// The `Result<_, ()>` return types mirror the ones of `Matchmaking`
+#[allow(clippy::result_unit_err)]
+impl MockMatchmaking {
+    /// Creates a lobby owned by the current user
+    ///
+    /// The `max_members` may not be higher than 250.
+    pub fn create_lobby<F>(&self, ty: LobbyType, max_members: u32, cb: F)
+    where
+        F: FnOnce(SResult<LobbyId>) + 'static + Send,
+    {
+        assert!(max_members <= 250); // Steam API limits
+        let lobby = {
+            let mut state = self.client.lock();
+            let id = MOCK_LOBBY_ID_BASE + state.next_lobby;
+            state.next_lobby += 1;
+            let owner = state.steam_id;
+            state.lobbies.insert(
+                id,
+                MockLobby {
+                    ty,
+                    max_members,
+                    owner,
+                    members: vec![owner],
+                    joinable: true,
+                    data: BTreeMap::new(),
+                },
+            );
+            LobbyId::from_raw(id)
+        };
+        self.client.queue(move || cb(Ok(lobby)));
+    }
+
+    /// Tries to join the lobby with the given ID
+    ///
+    /// Fails if the lobby doesn't exist, is full or isn't joinable.
+    pub fn join_lobby<F>(&self, lobby: LobbyId, cb: F)
+    where
+        F: FnOnce(Result<LobbyId, ()>) + 'static + Send,
+    {
+        let result = {
+            let mut state = self.client.lock();
+            let steam_id = state.steam_id;
+            match state.lobbies.get_mut(&lobby.raw()) {
+                Some(l) if l.members.contains(&steam_id) => Ok(lobby),
+                Some(l) if l.joinable && (l.members.len() as u32) < l.max_members => {
+                    l.members.push(steam_id);
+                    Ok(lobby)
+                }
+                _ => Err(()),
+            }
+        };
+        self.client.queue(move || cb(result));
+    }
+
+    /// Returns the public lobbies that can be joined
+    pub fn request_lobby_list<F>(&self, cb: F)
+    where
+        F: FnOnce(SResult<Vec<LobbyId>>) + 'static + Send,
+    {
+        let lobbies = self
+            .client
+            .lock()
+            .lobbies
+            .iter()
+            .filter(|(_, l)| {
+                l.ty == LobbyType::Public && l.joinable && (l.members.len() as u32) < l.max_members
+            })
+            .map(|(id, _)| LobbyId::from_raw(*id))
+            .collect();
+        self.client.queue(move || cb(Ok(lobbies)));
+    }
+
+    /// Returns the number of data keys in the lobby
+    pub fn lobby_data_count(&self, lobby: LobbyId) -> u32 {
+        self.with_lobby(lobby, |l| l.data.len() as u32).unwrap_or(0)
+    }
+
+    /// Returns the lobby metadata associated with the specified key
+    ///
+    /// The returned string stays valid until this accessor is dropped.
+    /// Every value read is kept until then, values that are read again
+    /// without having been changed are not stored twice.
+    pub fn lobby_data(&self, lobby: LobbyId, key: &str) -> Option<&str> {
+        let value = self
+            .with_lobby(lobby, |l| l.data.get(key).cloned())
+            .flatten()?;
+        let ptr: *const str = &*value;
+        self.strings
+            .lock()
+            .unwrap()
+            .entry(ptr as *const u8 as usize)
+            .or_insert(value);
+        // The string is kept alive by `strings` until this accessor is
+        // dropped and never moves, as it's allocated behind an `Arc`
+        Some(unsafe { &*ptr })
+    }
+
+    /// Returns the lobby metadata associated with the specified index
+    pub fn lobby_data_by_index(&self, lobby: LobbyId, idx: u32) -> Option<(String, String)> {
+        self.with_lobby(lobby, |l| {
+            l.data
+                .iter()
+                .nth(idx as usize)
+                .map(|(k, v)| (k.clone(), v.to_string()))
+        })
+        .flatten()
+    }
+
+    /// Sets the lobby metadata associated with the specified key
+    ///
+    /// Only the owner of the lobby can set its data.
+    pub fn set_lobby_data(&self, lobby: LobbyId, key: &str, value: &str) -> bool {
+        self.with_owned_lobby(lobby, |l| {
+            // Like steam, empty values are the same as missing ones
+            if value.is_empty() {
+                l.data.remove(key);
+            } else {
+                l.data.insert(key.to_owned(), value.into());
+            }
+        })
+    }
+
+    /// Deletes the lobby metadata associated with the specified key
+    pub fn delete_lobby_data(&self, lobby: LobbyId, key: &str) -> bool {
+        self.with_owned_lobby(lobby, |l| {
+            l.data.remove(key);
+        })
+    }
+
+    /// Sets whether or not a lobby is joinable by other players
+    pub fn set_lobby_joinable(&self, lobby: LobbyId, joinable: bool) -> bool {
+        self.with_owned_lobby(lobby, |l| l.joinable = joinable)
+    }
+
+    /// Exits the passed lobby
+    ///
+    /// Ownership passes to the next member, the lobby is removed once
+    /// the last member leaves.
+    pub fn leave_lobby(&self, lobby: LobbyId) {
+        let mut state = self.client.lock();
+        let steam_id = state.steam_id;
+        let empty = match state.lobbies.get_mut(&lobby.raw()) {
+            Some(l) => {
+                l.members.retain(|m| *m != steam_id);
+                if l.owner == steam_id {
+                    l.owner = l.members.first().copied().unwrap_or(0);
+                }
+                l.members.is_empty()
+            }
+            None => false,
+        };
+        if empty {
+            state.lobbies.remove(&lobby.raw());
+        }
+    }
+
+    /// Returns the maximum number of members of the lobby
+    pub fn lobby_member_limit(&self, lobby: LobbyId) -> Option<usize> {
+        self.with_lobby(lobby, |l| l.max_members as usize)
+    }
+
+    /// Returns the current lobby owner
+    pub fn lobby_owner(&self, lobby: LobbyId) -> SteamId {
+        SteamId(self.with_lobby(lobby, |l| l.owner).unwrap_or(0))
+    }
+
+    /// Returns the number of players in the lobby
+    pub fn lobby_member_count(&self, lobby: LobbyId) -> usize {
+        self.with_lobby(lobby, |l| l.members.len()).unwrap_or(0)
+    }
+
+    /// Returns a list of members currently in the lobby
+    pub fn lobby_members(&self, lobby: LobbyId) -> Vec<SteamId> {
+        self.with_lobby(lobby, |l| l.members.iter().map(|m| SteamId(*m)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Adds another user to the lobby, e.g. to simulate other players
+    /// joining.
+    ///
+    /// Returns false if the lobby doesn't exist or is full.
+    pub fn add_lobby_member(&self, lobby: LobbyId, member: SteamId) -> bool {
+        let mut state = self.client.lock();
+        match state.lobbies.get_mut(&lobby.raw()) {
+            Some(l) if (l.members.len() as u32) < l.max_members => {
+                if !l.members.contains(&member.0) {
+                    l.members.push(member.0);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn with_lobby<R>(&self, lobby: LobbyId, f: impl FnOnce(&MockLobby) -> R) -> Option<R> {
+        self.client.lock().lobbies.get(&lobby.raw()).map(f)
+    }
+
+    fn with_owned_lobby(&self, lobby: LobbyId, f: impl FnOnce(&mut MockLobby)) -> bool {
+        let mut state = self.client.lock();
+        let steam_id = state.steam_id;
+        match state.lobbies.get_mut(&lobby.raw()) {
+            Some(l) if l.owner == steam_id => {
+                f(l);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The fake counterpart of `Inventory`
+pub struct MockInventory {
+    client: MockClient,
+}
+
+impl MockInventory {
+    /// Adds an item to the fake inventory and returns its instance id
+    pub fn grant_item(&self, definition: SteamItemDef, quantity: u16) -> SteamItemInstanceID {
+        let mut state = self.client.lock();
+        state.next_item += 1;
+        let item_id = state.next_item;
+        state.items.insert(
+            item_id,
+            SteamItemDetails {
+                item_id: SteamItemInstanceID(item_id),
+                definition,
+                quantity,
+                flags: 0,
+            },
+        );
+        SteamItemInstanceID(item_id)
+    }
+
+    /// Retrieves all items in the fake inventory.
+    pub fn get_all_items(&self) -> Result<Vec<SteamItemDetails>, InventoryError> {
+        Ok(self.client.lock().items.values().cloned().collect())
+    }
+
+    /// Consumes the given quantity of an item, removing it once none are left
+    pub fn consume_item(
+        &self,
+        item_id: SteamItemInstanceID,
+        quantity: u32,
+    ) -> Result<(), InventoryError> {
+        let mut state = self.client.lock();
+        let item = state
+            .items
+            .get_mut(&item_id.0)
+            .ok_or(InventoryError::InvalidInput)?;
+        if quantity > item.quantity as u32 {
+            return Err(InventoryError::InvalidInput);
+        }
+        item.quantity -= quantity as u16;
+        if item.quantity == 0 {
+            state.items.remove(&item_id.0);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_stats() {
+        let client = MockClient::new();
+        let stats = client.user_stats();
+        stats.define_stat_i32("wins", 0);
+        stats.define_achievement("ACH_WIN");
+
+        assert_eq!(stats.set_stat_i32("wins", 3), Ok(()));
+        assert_eq!(stats.get_stat_i32("wins"), Ok(3));
+        assert!(stats.get_stat_i32("losses").is_err());
+        assert!(stats.get_stat_f32("wins").is_err());
+
+        stats.achievement("ACH_WIN").set().unwrap();
+        assert_eq!(stats.achievement("ACH_WIN").get(), Ok(true));
+        assert!(stats.achievement("ACH_LOSE").set().is_err());
+
+        stats.reset_all_stats(true).unwrap();
+        assert_eq!(stats.get_stat_i32("wins"), Ok(0));
+        assert_eq!(stats.achievement("ACH_WIN").get(), Ok(false));
+    }
+
+    #[test]
+    fn test_mock_remote_storage() {
+        let client = MockClient::new();
+        let rs = client.remote_storage();
+        assert!(!rs.file("save.dat").exists());
+
+        rs.file("save.dat").write().write_all(b"hello").unwrap();
+        let mut data = String::new();
+        rs.file("save.dat")
+            .read()
+            .read_to_string(&mut data)
+            .unwrap();
+        assert_eq!(data, "hello");
+        assert_eq!(rs.files()[0].size, 5);
+        assert_eq!(rs.file("save.dat").timestamp(), 1);
+
+        assert!(rs.file("save.dat").delete());
+        assert!(rs.files().is_empty());
+    }
+
+    #[test]
+    fn test_mock_lobbies() {
+        let client = MockClient::new();
+        let mm = client.matchmaking();
+        let created = Arc::new(Mutex::new(None));
+        {
+            let created = created.clone();
+            mm.create_lobby(LobbyType::Public, 2, move |v| {
+                *created.lock().unwrap() = Some(v.unwrap())
+            });
+        }
+        assert!(created.lock().unwrap().is_none());
+        client.run_callbacks();
+        let lobby = created.lock().unwrap().unwrap();
+
+        assert!(mm.set_lobby_data(lobby, "map", "dust"));
+        let map = mm.lobby_data(lobby, "map");
+        assert!(mm.set_lobby_data(lobby, "map", "inferno"));
+        assert_eq!(map, Some("dust"));
+        assert_eq!(mm.lobby_data(lobby, "map"), Some("inferno"));
+        assert_eq!(mm.lobby_data(lobby, "mode"), None);
+        for _ in 0..10 {
+            assert_eq!(mm.lobby_data(lobby, "map"), Some("inferno"));
+        }
+        // Polling an unchanged value doesn't keep storing it again
+        assert_eq!(mm.strings.lock().unwrap().len(), 2);
+
+        let found = Arc::new(Mutex::new(None));
+        {
+            let found = found.clone();
+            mm.request_lobby_list(move |v| *found.lock().unwrap() = Some(v.unwrap()));
+        }
+        client.run_callbacks();
+        assert_eq!(*found.lock().unwrap(), Some(vec![lobby]));
+        assert_eq!(mm.lobby_owner(lobby), client.steam_id());
+
+        let other = SteamId::from_raw(76561197960287931);
+        assert!(mm.add_lobby_member(lobby, other));
+        assert!(!mm.add_lobby_member(lobby, SteamId::from_raw(76561197960287932)));
+
+        mm.leave_lobby(lobby);
+        assert_eq!(mm.lobby_owner(lobby), other);
+        assert_eq!(mm.lobby_members(lobby), vec![other]);
+    }
+
+    #[test]
+    fn test_mock_inventory() {
+        let client = MockClient::new();
+        let inventory = client.inventory();
+        let item = inventory.grant_item(SteamItemDef(100), 2);
+
+        inventory.consume_item(item.clone(), 1).unwrap();
+        assert_eq!(inventory.get_all_items().unwrap()[0].quantity, 1);
+        assert!(inventory.consume_item(item.clone(), 2).is_err());
+        inventory.consume_item(item, 1).unwrap();
+        assert!(inventory.get_all_items().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 4000;
+
+/// Access to the steam music interface
+pub struct Music<Manager> {
+    pub(crate) music: *mut sys::ISteamMusic,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+impl<Manager> Music<Manager> {
+    /// Returns whether Steam Music is enabled
+    pub fn is_enabled(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamMusic_BIsEnabled(self.music) }
+    }
+
+    /// Returns whether a song is currently playing
+    pub fn is_playing(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamMusic_BIsPlaying(self.music) }
+    }
+
+    /// Returns the current playback status of Steam Music
+    pub fn playback_status(&self) -> PlaybackStatus {
+        unsafe { sys::SteamAPI_ISteamMusic_GetPlaybackStatus(self.music).into() }
+    }
+
+    /// Resumes playback of the current song
+    pub fn play(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_Play(self.music) }
+    }
+
+    /// Pauses playback of the current song
+    pub fn pause(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_Pause(self.music) }
+    }
+
+    /// Plays the previous song in the playlist
+    pub fn play_previous(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_PlayPrevious(self.music) }
+    }
+
+    /// Plays the next song in the playlist
+    pub fn play_next(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_PlayNext(self.music) }
+    }
+
+    /// Sets the volume of Steam Music, between 0.0 and 1.0
+    pub fn set_volume(&self, volume: f32) {
+        unsafe { sys::SteamAPI_ISteamMusic_SetVolume(self.music, volume) }
+    }
+
+    /// Returns the volume of Steam Music, between 0.0 and 1.0
+    pub fn volume(&self) -> f32 {
+        unsafe { sys::SteamAPI_ISteamMusic_GetVolume(self.music) }
+    }
+}
+
+/// The playback status of Steam Music
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlaybackStatus {
+    Undefined,
+    Playing,
+    Paused,
+    Idle,
+}
+
+impl From<sys::AudioPlayback_Status> for PlaybackStatus {
+    fn from(status: sys::AudioPlayback_Status) -> Self {
+        match status {
+            sys::AudioPlayback_Status::AudioPlayback_Playing => PlaybackStatus::Playing,
+            sys::AudioPlayback_Status::AudioPlayback_Paused => PlaybackStatus::Paused,
+            sys::AudioPlayback_Status::AudioPlayback_Idle => PlaybackStatus::Idle,
+            _ => PlaybackStatus::Undefined,
+        }
+    }
+}
+
+/// Called when the playback status of Steam Music changed. The new
+/// status can be read with `Music::playback_status`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlaybackStatusHasChanged;
+
+unsafe impl Callback for PlaybackStatusHasChanged {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::PlaybackStatusHasChanged_t>() as i32;
+
+    unsafe fn from_raw(_: *mut c_void) -> Self {
+        PlaybackStatusHasChanged
+    }
+}
+
+/// Called when the volume of Steam Music changed
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeHasChanged {
+    /// The new volume, between 0.0 and 1.0
+    pub new_volume: f32,
+}
+
+unsafe impl Callback for VolumeHasChanged {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::VolumeHasChanged_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::VolumeHasChanged_t);
+        VolumeHasChanged {
+            new_volume: val.m_flNewVolume,
+        }
+    }
+}
@@ -197,7 +197,8 @@ impl<Manager: 'static> NetworkingMessages<Manager> {
                         callback(request);
                     }
                 },
-            );
+            )
+            .forget();
         }
     }
 
@@ -215,7 +216,8 @@ impl<Manager: 'static> NetworkingMessages<Manager> {
                 move |failed: NetworkingMessagesSessionFailed| {
                     callback(failed.info);
                 },
-            );
+            )
+            .forget();
         }
     }
 }
@@ -107,7 +107,8 @@ impl<Manager> NetworkingUtils<Manager> {
         unsafe {
             register_callback(&self.inner, move |status: RelayNetworkStatusCallback| {
                 callback(status.status);
-            });
+            })
+            .forget();
         }
     }
 }
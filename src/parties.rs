@@ -0,0 +1,397 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 5300;
+
+/// Access to the steam parties interface.
+///
+/// Parties let players advertise open slots in their game through a
+/// beacon, which shows up in the "Join Party" UI of steam.
+pub struct Parties<Manager> {
+    pub(crate) parties: *mut sys::ISteamParties,
+    pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
+}
+
+/// The id of a party beacon
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartyBeaconId(pub(crate) u64);
+
+impl PartyBeaconId {
+    /// Creates a `PartyBeaconId` from a raw 64 bit value.
+    pub fn from_raw(id: u64) -> PartyBeaconId {
+        PartyBeaconId(id)
+    }
+
+    /// Returns the raw 64 bit value of the beacon id
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The kind of location a beacon is posted to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BeaconLocationType {
+    Invalid,
+    ChatGroup,
+}
+
+/// A location a beacon can be posted to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BeaconLocation {
+    pub location_type: BeaconLocationType,
+    pub location_id: u64,
+}
+
+impl BeaconLocation {
+    fn from_sys(location: sys::SteamPartyBeaconLocation_t) -> BeaconLocation {
+        BeaconLocation {
+            location_type: match location.m_eType {
+                sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup => {
+                    BeaconLocationType::ChatGroup
+                }
+                _ => BeaconLocationType::Invalid,
+            },
+            location_id: location.m_ulLocationID,
+        }
+    }
+
+    fn to_sys(self) -> sys::SteamPartyBeaconLocation_t {
+        sys::SteamPartyBeaconLocation_t {
+            m_eType: match self.location_type {
+                BeaconLocationType::Invalid => {
+                    sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_Invalid
+                }
+                BeaconLocationType::ChatGroup => {
+                    sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup
+                }
+            },
+            m_ulLocationID: self.location_id,
+        }
+    }
+}
+
+/// Data that can be looked up for a `BeaconLocation`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BeaconLocationData {
+    Name,
+    IconURLSmall,
+    IconURLMedium,
+    IconURLLarge,
+}
+
+impl From<BeaconLocationData> for sys::ESteamPartyBeaconLocationData {
+    fn from(data: BeaconLocationData) -> Self {
+        match data {
+            BeaconLocationData::Name => {
+                sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataName
+            }
+            BeaconLocationData::IconURLSmall => {
+                sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataIconURLSmall
+            }
+            BeaconLocationData::IconURLMedium => {
+                sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataIconURLMedium
+            }
+            BeaconLocationData::IconURLLarge => {
+                sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataIconURLLarge
+            }
+        }
+    }
+}
+
+/// The details of a beacon returned by `Parties::beacon_details`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BeaconDetails {
+    pub owner: SteamId,
+    pub location: BeaconLocation,
+    pub metadata: String,
+}
+
+/// The result of `Parties::join_party`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JoinedParty {
+    pub beacon: PartyBeaconId,
+    pub owner: SteamId,
+    /// The connect string the owner passed to `Parties::create_beacon`,
+    /// used to join the owner's game
+    pub connect_string: String,
+}
+
+impl<Manager> Parties<Manager> {
+    /// Returns the beacons that are currently active
+    pub fn active_beacons(&self) -> Vec<PartyBeaconId> {
+        unsafe {
+            let count = sys::SteamAPI_ISteamParties_GetNumActiveBeacons(self.parties);
+            (0..count)
+                .map(|i| {
+                    PartyBeaconId(sys::SteamAPI_ISteamParties_GetBeaconByIndex(
+                        self.parties,
+                        i,
+                    ))
+                })
+                .collect()
+        }
+    }
+
+    /// Returns the owner, location and metadata of a beacon
+    pub fn beacon_details(&self, beacon: PartyBeaconId) -> Option<BeaconDetails> {
+        unsafe {
+            let mut owner: sys::CSteamID = std::mem::zeroed();
+            let mut location: sys::SteamPartyBeaconLocation_t = std::mem::zeroed();
+            let mut metadata = [0 as c_char; 8193];
+            if !sys::SteamAPI_ISteamParties_GetBeaconDetails(
+                self.parties,
+                beacon.0,
+                &mut owner,
+                &mut location,
+                metadata.as_mut_ptr(),
+                metadata.len() as _,
+            ) {
+                return None;
+            }
+            Some(BeaconDetails {
+                owner: SteamId(owner.m_steamid.m_unAll64Bits),
+                location: BeaconLocation::from_sys(location),
+                metadata: CStr::from_ptr(metadata.as_ptr())
+                    .to_string_lossy()
+                    .into_owned(),
+            })
+        }
+    }
+
+    /// Joins the party of a beacon, reserving a slot in it.
+    ///
+    /// Once this succeeds the returned connect string should be used to
+    /// join the game of the beacon's owner.
+    pub fn join_party<F>(&self, beacon: PartyBeaconId, cb: F)
+    where
+        F: FnOnce(Result<JoinedParty, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamParties_JoinParty(self.parties, beacon.0);
+            register_call_result::<sys::JoinPartyCallback_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 1,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(JoinedParty {
+                            beacon: PartyBeaconId(v.m_ulBeaconID),
+                            owner: SteamId(v.m_SteamIDBeaconOwner.m_steamid.m_unAll64Bits),
+                            connect_string: CStr::from_ptr(v.m_rgchConnectString.as_ptr())
+                                .to_string_lossy()
+                                .into_owned(),
+                        })
+                    })
+                },
+            );
+        }
+    }
+
+    /// Returns the locations the user can post a beacon to
+    pub fn available_beacon_locations(&self) -> Vec<BeaconLocation> {
+        unsafe {
+            let mut count = 0;
+            if !sys::SteamAPI_ISteamParties_GetNumAvailableBeaconLocations(self.parties, &mut count)
+            {
+                return Vec::new();
+            }
+            let mut locations = vec![std::mem::zeroed(); count as usize];
+            if !sys::SteamAPI_ISteamParties_GetAvailableBeaconLocations(
+                self.parties,
+                locations.as_mut_ptr(),
+                count,
+            ) {
+                return Vec::new();
+            }
+            locations
+                .into_iter()
+                .map(BeaconLocation::from_sys)
+                .collect()
+        }
+    }
+
+    /// Creates a beacon advertising `open_slots` slots at the given location.
+    ///
+    /// `connect_string` is handed to users joining through the beacon and
+    /// `metadata` is shown in the steam UI. When a user joins, a
+    /// `ReservationNotification` callback is sent.
+    pub fn create_beacon<F>(
+        &self,
+        open_slots: u32,
+        location: BeaconLocation,
+        connect_string: &str,
+        metadata: &str,
+        cb: F,
+    ) where
+        F: FnOnce(Result<PartyBeaconId, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let connect_string = CString::new(connect_string).unwrap();
+            let metadata = CString::new(metadata).unwrap();
+            let mut location = location.to_sys();
+            let api_call = sys::SteamAPI_ISteamParties_CreateBeacon(
+                self.parties,
+                open_slots,
+                &mut location,
+                connect_string.as_ptr(),
+                metadata.as_ptr(),
+            );
+            register_call_result::<sys::CreateBeaconCallback_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 2,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(PartyBeaconId(v.m_ulBeaconID))
+                    })
+                },
+            );
+        }
+    }
+
+    /// Tells steam that a user who reserved a slot through the beacon has
+    /// joined the game, filling the slot.
+    pub fn on_reservation_completed(&self, beacon: PartyBeaconId, user: SteamId) {
+        unsafe {
+            sys::SteamAPI_ISteamParties_OnReservationCompleted(self.parties, beacon.0, user.0)
+        }
+    }
+
+    /// Releases the slot a user reserved through the beacon, e.g. because
+    /// they never joined the game.
+    pub fn cancel_reservation(&self, beacon: PartyBeaconId, user: SteamId) {
+        unsafe { sys::SteamAPI_ISteamParties_CancelReservation(self.parties, beacon.0, user.0) }
+    }
+
+    /// Changes the number of open slots of the beacon
+    pub fn change_num_open_slots<F>(&self, beacon: PartyBeaconId, open_slots: u32, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamParties_ChangeNumOpenSlots(self.parties, beacon.0, open_slots);
+            register_call_result::<sys::ChangeNumOpenSlotsCallback_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 4,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Removes the beacon. This should be called when the game is full or
+    /// the party is no longer open.
+    pub fn destroy_beacon(&self, beacon: PartyBeaconId) -> bool {
+        unsafe { sys::SteamAPI_ISteamParties_DestroyBeacon(self.parties, beacon.0) }
+    }
+
+    /// Looks up data about a beacon location, such as its name
+    pub fn beacon_location_data(
+        &self,
+        location: BeaconLocation,
+        data: BeaconLocationData,
+    ) -> Option<String> {
+        unsafe {
+            let mut buffer = [0 as c_char; 1024];
+            if sys::SteamAPI_ISteamParties_GetBeaconLocationData(
+                self.parties,
+                location.to_sys(),
+                data.into(),
+                buffer.as_mut_ptr(),
+                buffer.len() as _,
+            ) {
+                Some(
+                    CStr::from_ptr(buffer.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    async_call_results! {
+        fn join_party_async => join_party(beacon: PartyBeaconId) -> Result<JoinedParty, SteamError>;
+        fn create_beacon_async => create_beacon(open_slots: u32, location: BeaconLocation, connect_string: &str, metadata: &str) -> Result<PartyBeaconId, SteamError>;
+        fn change_num_open_slots_async => change_num_open_slots(beacon: PartyBeaconId, open_slots: u32) -> Result<(), SteamError>;
+    }
+}
+
+/// Called when a user has reserved a slot through one of the user's
+/// beacons.
+///
+/// Once the user has joined the game `Parties::on_reservation_completed`
+/// should be called.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReservationNotification {
+    pub beacon: PartyBeaconId,
+    pub joiner: SteamId,
+}
+
+unsafe impl Callback for ReservationNotification {
+    const ID: i32 = CALLBACK_BASE_ID + 3;
+    const SIZE: i32 = ::std::mem::size_of::<sys::ReservationNotificationCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::ReservationNotificationCallback_t);
+        ReservationNotification {
+            beacon: PartyBeaconId(val.m_ulBeaconID),
+            joiner: SteamId(val.m_steamIDJoiner.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
+/// Called when the list of available beacon locations has changed
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AvailableBeaconLocationsUpdated;
+
+unsafe impl Callback for AvailableBeaconLocationsUpdated {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::AvailableBeaconLocationsUpdated_t>() as i32;
+
+    unsafe fn from_raw(_: *mut c_void) -> Self {
+        AvailableBeaconLocationsUpdated
+    }
+}
+
+/// Called when the list of active beacons has changed
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ActiveBeaconsUpdated;
+
+unsafe impl Callback for ActiveBeaconsUpdated {
+    const ID: i32 = CALLBACK_BASE_ID + 6;
+    const SIZE: i32 = ::std::mem::size_of::<sys::ActiveBeaconsUpdated_t>() as i32;
+
+    unsafe fn from_raw(_: *mut c_void) -> Self {
+        ActiveBeaconsUpdated
+    }
+}
@@ -0,0 +1,137 @@
+//! Access to the raw interfaces behind the wrappers, enabled with the
+//! `raw-bindings` feature.
+//!
+//! This allows calling functions of the sdk that aren't wrapped by this
+//! crate yet through `steamworks::sys`, while still using the crate to
+//! initialize the api and to dispatch callbacks.
+//!
+//! # Callbacks
+//!
+//! Callbacks triggered by raw calls are dispatched by `run_callbacks` like
+//! any other. They can be received by implementing `Callback` for a type
+//! converting the `sys` struct and passing it to `register_callback`.
+//! Results of raw calls returning a `SteamAPICall_t` can be received with
+//! `Client::register_raw_call_result`.
+
+use super::*;
+use crate::networking_messages::NetworkingMessages;
+use crate::networking_sockets::NetworkingSockets;
+use crate::networking_utils::NetworkingUtils;
+
+macro_rules! raw_interfaces {
+    ($($ty:ident . $field:ident => $raw:ident,)*) => {
+        $(
+            impl<Manager> $ty<Manager> {
+                #[doc = concat!("Returns the raw `", stringify!($raw), "` interface pointer")]
+                ///
+                /// # Safety
+                ///
+                /// The pointer is only valid while a client or server handle
+                /// of the same instance is alive, it must not be used after
+                /// the steam api has been shut down. Functions called through
+                /// it have the same requirements as documented by the sdk.
+                pub unsafe fn raw(&self) -> *mut sys::$raw {
+                    self.$field
+                }
+            }
+        )*
+    };
+}
+
+raw_interfaces! {
+    Apps.apps => ISteamApps,
+    Friends.friends => ISteamFriends,
+    GameSearch.search => ISteamGameSearch,
+    GameServerStats.stats => ISteamGameServerStats,
+    HTMLSurface.html => ISteamHTMLSurface,
+    Input.input => ISteamInput,
+    Inventory.inventory => ISteamInventory,
+    Matchmaking.mm => ISteamMatchmaking,
+    MatchmakingServers.mms => ISteamMatchmakingServers,
+    Music.music => ISteamMusic,
+    Networking.net => ISteamNetworking,
+    NetworkingMessages.net => ISteamNetworkingMessages,
+    NetworkingSockets.sockets => ISteamNetworkingSockets,
+    NetworkingUtils.utils => ISteamNetworkingUtils,
+    Parties.parties => ISteamParties,
+    RemotePlay.rp => ISteamRemotePlay,
+    RemoteStorage.rs => ISteamRemoteStorage,
+    Screenshots.screenshots => ISteamScreenshots,
+    Timeline.timeline => ISteamTimeline,
+    UGC.ugc => ISteamUGC,
+    User.user => ISteamUser,
+    UserStats.user_stats => ISteamUserStats,
+    Utils.utils => ISteamUtils,
+    Video.video => ISteamVideo,
+}
+
+impl Server {
+    /// Returns the raw `ISteamGameServer` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while the server is alive, it must not
+    /// be used after the steam api has been shut down. Functions called
+    /// through it have the same requirements as documented by the sdk.
+    pub unsafe fn raw(&self) -> *mut sys::ISteamGameServer {
+        self.server
+    }
+
+    /// Returns the raw user handle of the game server
+    ///
+    /// # Safety
+    ///
+    /// The handle is only valid while the server is alive.
+    pub unsafe fn raw_user(&self) -> sys::HSteamUser {
+        sys::SteamGameServer_GetHSteamUser()
+    }
+}
+
+impl Client<ClientManager> {
+    /// Returns the raw user handle of the client, needed by some of the
+    /// sdk's functions
+    ///
+    /// # Safety
+    ///
+    /// The handle is only valid while a client of the same instance is
+    /// alive.
+    pub unsafe fn raw_user(&self) -> sys::HSteamUser {
+        sys::SteamAPI_GetHSteamUser()
+    }
+}
+
+impl<Manager> Client<Manager>
+where
+    Manager: crate::Manager,
+{
+    /// Returns the raw pipe handle that the callbacks of this instance
+    /// are dispatched from
+    ///
+    /// # Safety
+    ///
+    /// The handle is only valid while a client of the same instance is
+    /// alive. Callbacks must not be dispatched from it manually, as that
+    /// would take them away from `run_callbacks`.
+    pub unsafe fn raw_pipe(&self) -> sys::HSteamPipe {
+        Manager::get_pipe()
+    }
+
+    /// Registers a function to be called with the result of a raw call
+    /// returning a `SteamAPICall_t`.
+    ///
+    /// The function receives the result and the error if the call failed,
+    /// e.g. because steam failed to deliver it (`SteamError::IOFailure`),
+    /// at which point the result is zeroed.
+    ///
+    /// # Safety
+    ///
+    /// `C` must be the `sys` struct that steam returns as the result of
+    /// the call, e.g. `sys::LobbyCreated_t` for `ISteamMatchmaking::CreateLobby`.
+    pub unsafe fn register_raw_call_result<C, F>(&self, api_call: sys::SteamAPICall_t, f: F)
+    where
+        C: 'static,
+        F: FnOnce(&C, Option<SteamError>) + 'static + Send,
+    {
+        register_call_result::<C, _, _>(&self.inner, &CallOptions::default(), api_call, 0, f)
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 
+/// Access to the steam remote play interface
 pub struct RemotePlay<Manager> {
     pub(crate) rp: *mut sys::ISteamRemotePlay,
     pub(crate) inner: Arc<Inner<Manager>>,
@@ -36,6 +37,14 @@ impl<Manager> RemotePlay<Manager> {
         }
     }
 
+    /// Starts a Remote Play Together session, optionally showing the overlay
+    /// so the user can invite friends.
+    ///
+    /// Returns `false` if Remote Play Together isn't available.
+    pub fn start_remote_play_together(&self, show_overlay: bool) -> bool {
+        unsafe { sys::SteamAPI_ISteamRemotePlay_BStartRemotePlayTogether(self.rp, show_overlay) }
+    }
+
     /// Get a remote play session from a session ID. The session may or may not be valid or active
     pub fn session(&self, session: RemotePlaySessionId) -> RemotePlaySession<Manager> {
         RemotePlaySession {
@@ -74,12 +83,14 @@ pub struct RemotePlaySession<Manager> {
     pub(crate) _inner: Arc<Inner<Manager>>,
 }
 
+/// The type of device a Remote Play client is running on
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SteamDeviceFormFactor {
     Phone,
     Tablet,
     Computer,
     TV,
+    VRHeadset,
 }
 
 impl<Manager> RemotePlaySession<Manager> {
@@ -122,6 +133,7 @@ impl<Manager> RemotePlaySession<Manager> {
                 sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorTablet => Some(Tablet),
                 sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorComputer => Some(Computer),
                 sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorTV => Some(TV),
+                sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorVRHeadset => Some(VRHeadset),
                 _ => None,
             }
         }
@@ -190,3 +202,26 @@ unsafe impl Callback for RemotePlayDisconnected {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A guest without steam was invited to Remote Play Together. The game
+/// should show the URL so it can be shared with the guest.
+pub struct RemotePlayTogetherGuestInvite {
+    /// The URL the guest can use to join the session
+    pub connect_url: String,
+}
+
+unsafe impl Callback for RemotePlayTogetherGuestInvite {
+    const ID: i32 = sys::SteamRemotePlayTogetherGuestInvite_t_k_iCallback as i32;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamRemotePlayTogetherGuestInvite_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamRemotePlayTogetherGuestInvite_t);
+        RemotePlayTogetherGuestInvite {
+            connect_url: CStr::from_ptr(val.m_szConnectURL.as_ptr())
+                .to_string_lossy()
+                .into_owned(),
+        }
+    }
+}
@@ -10,6 +10,7 @@ pub struct RemoteStorage<Manager> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PublishedFileVisibility {
     Public,
     FriendsOnly,
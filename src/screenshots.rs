@@ -16,9 +16,30 @@ impl<Manager> Screenshots<Manager> {
     /// Hooking is disabled by default, and only ever enabled if you do so with this function.
     ///
     /// If the hooking is enabled, then the [`ScreenshotRequested`] callback will be sent if the user presses the hotkey or when [`Self::trigger_screenshot`] is called,
-    /// and then the game is expected to call `WriteScreenshot` or [`Self::add_screenshot_to_library`] in response.
+    /// and then the game is expected to call [`Self::write_screenshot`] or [`Self::add_screenshot_to_library`] in response.
     ///
     /// You can check if hooking is enabled with [`Self::is_screenshots_hooked`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use steamworks::*;
+    /// # use steamworks::screenshots::*;
+    /// # use std::path::Path;
+    /// # let client = steamworks::Client::init().unwrap();
+    /// client.screenshots().hook_screenshots(true);
+    ///
+    /// let capture_client = client.clone();
+    /// let callback_handle = client.register_callback(move |_: ScreenshotRequested| {
+    ///     // Capture and save the frame yourself, e.g. at a higher resolution
+    ///     let _ = capture_client.screenshots().add_screenshot_to_library(
+    ///         Path::new("screenshot.jpg"),
+    ///         None,
+    ///         3840,
+    ///         2160,
+    ///     );
+    /// });
+    /// ```
     pub fn hook_screenshots(&self, hook: bool) {
         unsafe {
             sys::SteamAPI_ISteamScreenshots_HookScreenshots(self.screenshots, hook);
@@ -48,8 +69,46 @@ impl<Manager> Screenshots<Manager> {
         }
     }
 
+    /// Writes a screenshot to the user's Steam screenshot library given the raw image data,
+    /// which must be in RGB format (3 bytes per pixel).
+    ///
+    /// The handle to this screenshot that is valid for the duration of the game process and can be used to apply tags.
+    ///
+    /// This call is asynchronous, a [`ScreenshotReady`] callback will be sent when the screenshot has finished writing to disk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rgb` is not `width * height * 3` bytes long.
+    pub fn write_screenshot(
+        &self,
+        rgb: &[u8],
+        width: i32,
+        height: i32,
+    ) -> Result<ScreenshotHandle, ScreenshotLibraryAddError> {
+        assert_eq!(rgb.len(), width as usize * height as usize * 3);
+        unsafe {
+            // Steam does not modify the buffer despite taking a mutable pointer.
+            let handle = sys::SteamAPI_ISteamScreenshots_WriteScreenshot(
+                self.screenshots,
+                rgb.as_ptr() as *mut _,
+                rgb.len() as u32,
+                width,
+                height,
+            );
+
+            if handle != sys::INVALID_SCREENSHOT_HANDLE {
+                Ok(handle)
+            } else {
+                Err(ScreenshotLibraryAddError::SavingFailed)
+            }
+        }
+    }
+
     /// Adds a screenshot to the user's Steam screenshot library from disk.
     ///
+    /// The file must be a JPEG, TGA or PNG. If no thumbnail is provided, Steam generates one
+    /// from the screenshot.
+    ///
     /// Triggers a [`ScreenshotReady`] callback.
     /// The handle to this screenshot that is valid for the duration of the game process and can be used to apply tags.
     ///
@@ -89,6 +148,39 @@ impl<Manager> Screenshots<Manager> {
             }
         }
     }
+
+    /// Sets the location metadata of a screenshot, e.g. the name of the map or level it was
+    /// taken in. The location can be up to 255 characters long.
+    ///
+    /// Returns `false` if the handle is invalid or the location is too long.
+    pub fn set_location(&self, screenshot: ScreenshotHandle, location: &str) -> bool {
+        let location = CString::new(location).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_SetLocation(
+                self.screenshots,
+                screenshot,
+                location.as_ptr(),
+            )
+        }
+    }
+
+    /// Tags a user as being visible in the screenshot.
+    ///
+    /// Up to 32 users can be tagged in a single screenshot. Returns `false` if the handle is
+    /// invalid or too many users have been tagged.
+    pub fn tag_user(&self, screenshot: ScreenshotHandle, user: SteamId) -> bool {
+        unsafe { sys::SteamAPI_ISteamScreenshots_TagUser(self.screenshots, screenshot, user.0) }
+    }
+
+    /// Tags a published workshop file as being visible in the screenshot.
+    ///
+    /// Up to 32 files can be tagged in a single screenshot. Returns `false` if the handle is
+    /// invalid or too many files have been tagged.
+    pub fn tag_published_file(&self, screenshot: ScreenshotHandle, file: PublishedFileId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_TagPublishedFile(self.screenshots, screenshot, file.0)
+        }
+    }
 }
 
 #[derive(Debug, Error)]
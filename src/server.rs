@@ -2,16 +2,23 @@ use super::*;
 use crate::networking_types::NetworkingIdentity;
 #[cfg(test)]
 use serial_test::serial;
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, SocketAddrV4};
 
 /// The main entry point into the steam client for servers.
 ///
 /// This provides access to all of the steamworks api that
 /// servers can use.
+///
+/// A server has its own pipe to steam and its own set of registered
+/// callbacks, separate from those of a `Client` created with
+/// `Client::init`. This allows a single process to act as both a
+/// client and a server (a listen server), as long as the callbacks of
+/// both are run.
 #[derive(Clone)]
 pub struct Server {
     inner: Arc<Inner<ServerManager>>,
-    server: *mut sys::ISteamGameServer,
+    pub(crate) server: *mut sys::ISteamGameServer,
+    options: CallOptions,
 }
 
 unsafe impl Send for Server {}
@@ -36,6 +43,26 @@ pub enum ServerMode {
     AuthenticationAndSecure,
 }
 
+/// Passed as the query port to `Server::init` to share the game port
+/// with the query port. Query packets then have to be forwarded with
+/// `Server::handle_incoming_packet`.
+pub const QUERY_PORT_SHARED: u16 = sys::STEAMGAMESERVER_QUERY_PORT_SHARED;
+
+/// The result of `Server::compute_new_player_compatibility`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayerCompatibility {
+    /// The player that was checked
+    pub candidate: SteamId,
+    /// The number of players on the server that don't like the candidate
+    pub players_that_dont_like_candidate: i32,
+    /// The number of players on the server that the candidate doesn't like
+    pub players_that_candidate_doesnt_like: i32,
+    /// The number of players on the server that are members of the
+    /// associated clan and don't like the candidate
+    pub clan_players_that_dont_like_candidate: i32,
+}
+
 impl Server {
     fn steam_game_server_init_ex(
         un_ip: std::ffi::c_uint,
@@ -94,6 +121,34 @@ impl Server {
     /// * The game isn't running on the same user/level as the steam client
     /// * The user doesn't own a license for the game.
     /// * The app ID isn't completely set up.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use steamworks::*;
+    /// let (server, client) = Server::init(
+    ///     [0, 0, 0, 0].into(),
+    ///     27015,
+    ///     27016,
+    ///     ServerMode::Authentication,
+    ///     "1.0.0",
+    /// )
+    /// .unwrap();
+    ///
+    /// server.set_product("480");
+    /// server.set_game_description("My game");
+    /// server.set_dedicated_server(true);
+    ///
+    /// let _cb = server.register_callback(|_: SteamServersConnected| {
+    ///     println!("Connected to steam");
+    /// });
+    /// server.log_on_anonymous();
+    ///
+    /// loop {
+    ///     client.run_callbacks();
+    ///     std::thread::sleep(std::time::Duration::from_millis(100));
+    /// }
+    /// ```
     pub fn init(
         ip: Ipv4Addr,
         game_port: u16,
@@ -135,8 +190,11 @@ impl Server {
                 _manager: ServerManager { _priv: () },
                 callbacks: Mutex::new(Callbacks {
                     callbacks: HashMap::new(),
+                    forgotten: HashMap::new(),
                     call_results: HashMap::new(),
+                    policy: Default::default(),
                 }),
+                pump_taken: AtomicBool::new(false),
                 networking_sockets_data: Mutex::new(NetworkingSocketsData {
                     sockets: Default::default(),
                     independent_connections: Default::default(),
@@ -147,17 +205,45 @@ impl Server {
                 Server {
                     inner: server.clone(),
                     server: server_raw,
+                    options: CallOptions::default(),
                 },
                 Client { inner: server },
             ))
         }
     }
 
+    /// Runs any currently pending callbacks of the server
+    ///
+    /// This is the same as calling `run_callbacks` on the client
+    /// returned by `init`, which does nothing and returns `false` while
+    /// the `CallbackPump` is taken or callbacks are already being run.
+    pub fn run_callbacks(&self) -> bool {
+        run_callbacks_through_pump(&self.inner)
+    }
+
+    /// Takes the `CallbackPump` that runs the callbacks of the server.
+    ///
+    /// The server and the client returned by `init` share the same pump.
+    pub fn callback_pump(&self) -> Option<CallbackPump<ServerManager>> {
+        take_callback_pump(&self.inner)
+    }
+
+    /// Applies the options to the operations started through the
+    /// returned server
+    pub fn with_call_options(mut self, options: CallOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Registers the passed function as a callback for the
     /// given type.
     ///
     /// The callback will be run on the thread that `run_callbacks`
     /// is called when the event arrives.
+    ///
+    /// The callback stays registered until the returned handle is dropped.
+    /// Any number of callbacks can be registered for the same type, they
+    /// are run in the order they were registered.
     pub fn register_callback<C, F>(&self, f: F) -> CallbackHandle<ServerManager>
     where
         C: Callback,
@@ -166,6 +252,16 @@ impl Server {
         unsafe { register_callback(&self.inner, f) }
     }
 
+    /// Returns a stream of the callback events of the given type.
+    ///
+    /// See `Client::callback_stream`.
+    pub fn callback_stream<C>(&self) -> CallbackStream<C, ServerManager>
+    where
+        C: Callback + Send + 'static,
+    {
+        unsafe { register_callback_stream(&self.inner) }
+    }
+
     /// Returns the steam id of the current server
     pub fn steam_id(&self) -> SteamId {
         unsafe { SteamId(sys::SteamAPI_ISteamGameServer_GetSteamID(self.server)) }
@@ -271,6 +367,50 @@ impl Server {
         }
     }
 
+    /// Passes a packet received on the game port to steam.
+    ///
+    /// When the server shares its game port with the query port
+    /// (`query_port` set to `QUERY_PORT_SHARED` in `init`), any packets
+    /// that the game doesn't recognize should be passed here. Returns
+    /// whether steam handled the packet.
+    pub fn handle_incoming_packet(&self, data: &[u8], from: SocketAddrV4) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_HandleIncomingPacket(
+                self.server,
+                data.as_ptr() as *const _,
+                data.len() as _,
+                (*from.ip()).into(),
+                from.port(),
+            )
+        }
+    }
+
+    /// Returns the next packet steam wants to send through the game port
+    /// along with the address to send it to, or `None` once there are no
+    /// more packets.
+    ///
+    /// This should be called after `handle_incoming_packet` until it
+    /// returns `None`.
+    pub fn next_outgoing_packet(&self) -> Option<(Vec<u8>, SocketAddrV4)> {
+        unsafe {
+            let mut packet = vec![0u8; 16 * 1024];
+            let mut ip = 0;
+            let mut port = 0;
+            let len = sys::SteamAPI_ISteamGameServer_GetNextOutgoingPacket(
+                self.server,
+                packet.as_mut_ptr() as *mut _,
+                packet.len() as _,
+                &mut ip,
+                &mut port,
+            );
+            if len <= 0 {
+                return None;
+            }
+            packet.truncate(len as usize);
+            Some((packet, SocketAddrV4::new(ip.into(), port)))
+        }
+    }
+
     /// Sets the game product identifier. This is currently used by the master server for version
     /// checking purposes. Converting the games app ID to a string for this is recommended.
     ///
@@ -316,14 +456,45 @@ impl Server {
         }
     }
 
+    /// Logs the server off from the Steam servers.
+    ///
+    /// A `SteamServersDisconnected` callback is sent once this completes.
+    pub fn log_off(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_LogOff(self.server);
+        }
+    }
+
+    /// Returns whether the server is currently logged on to the Steam servers
+    pub fn is_logged_on(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamGameServer_BLoggedOn(self.server) }
+    }
+
+    /// Returns whether the server is VAC secure. This is only known once
+    /// the server has logged on.
+    pub fn is_secure(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamGameServer_BSecure(self.server) }
+    }
+
+    /// Returns whether the master server has requested a restart, which
+    /// happens when a newer version of the server is available.
+    pub fn was_restart_requested(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamGameServer_WasRestartRequested(self.server) }
+    }
+
     /// If active, updates the master server with this server's presence so players can find it via
     /// the steam matchmaking/server browser interfaces.
-    pub fn enable_heartbeats(&self, active: bool) {
+    pub fn set_advertise_server_active(&self, active: bool) {
         unsafe {
             sys::SteamAPI_ISteamGameServer_SetAdvertiseServerActive(self.server, active);
         }
     }
 
+    /// Same as `set_advertise_server_active`
+    pub fn enable_heartbeats(&self, active: bool) {
+        self.set_advertise_server_active(active);
+    }
+
     /// If your game is a "mod," pass the string that identifies it.  The default is an empty
     /// string, meaning this application is the original game, not a mod.
     pub fn set_mod_dir(&self, mod_dir: &str) {
@@ -358,6 +529,22 @@ impl Server {
         }
     }
 
+    /// Sets the number of bot or AI players on the server.
+    ///
+    /// This value may be changed at any time.
+    pub fn set_bot_player_count(&self, count: i32) {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_SetBotPlayerCount(self.server, count);
+        }
+    }
+
+    /// Sets whether the server requires a password to join
+    pub fn set_password_protected(&self, password_protected: bool) {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_SetPasswordProtected(self.server, password_protected);
+        }
+    }
+
     /// Sets a string defining the "gametags" for this server, this is optional, but if set it
     /// allows users to filter in the matchmaking/server-browser interfaces based on the value.
     ///
@@ -378,6 +565,30 @@ impl Server {
         }
     }
 
+    /// Sets a string defining the "gamedata" for this server, this is optional, but if set it
+    /// allows users to filter in the matchmaking/server-browser interfaces based on the value.
+    ///
+    /// Unlike the game tags this is not visible to players in the server browser.
+    ///
+    /// This can not be longer than 2047.
+    pub fn set_game_data(&self, data: &str) {
+        assert!(data.len() < 2048, "data can not be longer than 2047.");
+
+        let data = CString::new(data).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_SetGameData(self.server, data.as_ptr());
+        }
+    }
+
+    /// Sets the region identifier reported to the server browser. By default this
+    /// is determined automatically.
+    pub fn set_region(&self, region: &str) {
+        let region = CString::new(region).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_SetRegion(self.server, region.as_ptr());
+        }
+    }
+
     /// Add/update a rules key/value pair.
     pub fn set_key_value(&self, key: &str, value: &str) {
         let key = CString::new(key).unwrap();
@@ -395,6 +606,71 @@ impl Server {
         }
     }
 
+    /// Associates the server with a steam group (clan).
+    ///
+    /// Members of the group are treated as friends by
+    /// `compute_new_player_compatibility`, allowing community servers to
+    /// prioritize them.
+    pub fn associate_with_clan<F>(&self, clan: SteamId, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamGameServer_AssociateWithClan(self.server, clan.0);
+            register_call_result::<sys::AssociateWithClanResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                210,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Checks how compatible a player is with the players currently on the
+    /// server, based on who they have blocked or marked as liked.
+    ///
+    /// This can be used to decide whether to let a player join a full
+    /// server.
+    pub fn compute_new_player_compatibility<F>(&self, player: SteamId, cb: F)
+    where
+        F: FnOnce(Result<PlayerCompatibility, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamGameServer_ComputeNewPlayerCompatibility(self.server, player.0);
+            register_call_result::<sys::ComputeNewPlayerCompatibilityResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                211,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(PlayerCompatibility {
+                            candidate: SteamId(v.m_SteamIDCandidate.m_steamid.m_unAll64Bits),
+                            players_that_dont_like_candidate: v.m_cPlayersThatDontLikeCandidate,
+                            players_that_candidate_doesnt_like: v.m_cPlayersThatCandidateDoesntLike,
+                            clan_players_that_dont_like_candidate: v
+                                .m_cClanPlayersThatDontLikeCandidate,
+                        })
+                    })
+                },
+            );
+        }
+    }
+
     /// Returns an accessor to the steam UGC interface (steam workshop)
     ///
     /// **For this to work properly, you need to call `UGC::init_for_game_server()`!**
@@ -405,6 +681,20 @@ impl Server {
             UGC {
                 ugc,
                 inner: self.inner.clone(),
+                options: CallOptions::default(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam game server stats interface
+    pub fn stats(&self) -> GameServerStats<ServerManager> {
+        unsafe {
+            let stats = sys::SteamAPI_SteamGameServerStats_v001();
+            debug_assert!(!stats.is_null());
+            GameServerStats {
+                stats,
+                inner: self.inner.clone(),
+                options: CallOptions::default(),
             }
         }
     }
@@ -422,6 +712,11 @@ impl Server {
         }
     }
     */
+
+    async_call_results! {
+        fn associate_with_clan_async => associate_with_clan(clan: SteamId) -> Result<(), SteamError>;
+        fn compute_new_player_compatibility_async => compute_new_player_compatibility(player: SteamId) -> Result<PlayerCompatibility, SteamError>;
+    }
 }
 
 #[test]
@@ -0,0 +1,251 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 1800;
+
+/// Access to the steam game server stats interface.
+///
+/// This allows a trusted game server to read and write the stats and
+/// achievements of the users connected to it.
+pub struct GameServerStats<Manager> {
+    pub(crate) stats: *mut sys::ISteamGameServerStats,
+    pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
+}
+
+impl<Manager> GameServerStats<Manager> {
+    /// Downloads the stats and achievements of the given user.
+    ///
+    /// This must complete successfully before any of the other functions
+    /// can be used for the user. The stats stay loaded until the user
+    /// disconnects, after which a `GSStatsUnloaded` callback is sent.
+    pub fn request_user_stats<F>(&self, user: SteamId, cb: F)
+    where
+        F: FnOnce(Result<SteamId, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamGameServerStats_RequestUserStats(self.stats, user.0);
+            register_call_result::<sys::GSStatsReceived_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(SteamId(v.m_steamIDUser.m_steamid.m_unAll64Bits))
+                    })
+                },
+            );
+        }
+    }
+
+    /// Gets the value of a given stat for the user
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have
+    /// completed successfully for the user.
+    pub fn get_user_stat_i32(&self, user: SteamId, name: &str) -> Option<i32> {
+        let name = CString::new(name).unwrap();
+
+        let mut value: i32 = 0;
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_GetUserStatInt32(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+                &mut value,
+            )
+        };
+        if success {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the value of a given stat for the user
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have
+    /// completed successfully for the user.
+    pub fn get_user_stat_f32(&self, user: SteamId, name: &str) -> Option<f32> {
+        let name = CString::new(name).unwrap();
+
+        let mut value: f32 = 0.0;
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_GetUserStatFloat(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+                &mut value,
+            )
+        };
+        if success {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Sets / updates the value of a given stat for the user
+    ///
+    /// This call only changes the value in-memory. To commit the stats you
+    /// must call [`store_user_stats()`](#method.store_user_stats)
+    pub fn set_user_stat_i32(&self, user: SteamId, name: &str, stat: i32) -> bool {
+        let name = CString::new(name).unwrap();
+
+        unsafe {
+            sys::SteamAPI_ISteamGameServerStats_SetUserStatInt32(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+                stat,
+            )
+        }
+    }
+
+    /// Sets / updates the value of a given stat for the user
+    ///
+    /// This call only changes the value in-memory. To commit the stats you
+    /// must call [`store_user_stats()`](#method.store_user_stats)
+    pub fn set_user_stat_f32(&self, user: SteamId, name: &str, stat: f32) -> bool {
+        let name = CString::new(name).unwrap();
+
+        unsafe {
+            sys::SteamAPI_ISteamGameServerStats_SetUserStatFloat(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+                stat,
+            )
+        }
+    }
+
+    /// Updates an average rate stat for the user, e.g. kills per hour.
+    ///
+    /// `count_this_session` is the value accumulated during the session
+    /// and `session_length` is the length of the session in seconds.
+    pub fn update_user_avg_rate_stat(
+        &self,
+        user: SteamId,
+        name: &str,
+        count_this_session: f32,
+        session_length: f64,
+    ) -> bool {
+        let name = CString::new(name).unwrap();
+
+        unsafe {
+            sys::SteamAPI_ISteamGameServerStats_UpdateUserAvgRateStat(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+                count_this_session,
+                session_length,
+            )
+        }
+    }
+
+    /// Returns whether the user has unlocked the given achievement
+    pub fn get_user_achievement(&self, user: SteamId, name: &str) -> Option<bool> {
+        let name = CString::new(name).unwrap();
+
+        let mut achieved = false;
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_GetUserAchievement(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+                &mut achieved,
+            )
+        };
+        if success {
+            Some(achieved)
+        } else {
+            None
+        }
+    }
+
+    /// Unlocks an achievement for the user.
+    ///
+    /// To commit the change you must call
+    /// [`store_user_stats()`](#method.store_user_stats)
+    pub fn set_user_achievement(&self, user: SteamId, name: &str) -> bool {
+        let name = CString::new(name).unwrap();
+
+        unsafe {
+            sys::SteamAPI_ISteamGameServerStats_SetUserAchievement(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+            )
+        }
+    }
+
+    /// Resets the unlock status of an achievement for the user.
+    ///
+    /// To commit the change you must call
+    /// [`store_user_stats()`](#method.store_user_stats)
+    pub fn clear_user_achievement(&self, user: SteamId, name: &str) -> bool {
+        let name = CString::new(name).unwrap();
+
+        unsafe {
+            sys::SteamAPI_ISteamGameServerStats_ClearUserAchievement(
+                self.stats,
+                user.0,
+                name.as_ptr(),
+            )
+        }
+    }
+
+    /// Sends the changed stats and achievements of the user to the server
+    /// for permanent storage.
+    pub fn store_user_stats<F>(&self, user: SteamId, cb: F)
+    where
+        F: FnOnce(Result<SteamId, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamGameServerStats_StoreUserStats(self.stats, user.0);
+            register_call_result::<sys::GSStatsStored_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 1,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(SteamId(v.m_steamIDUser.m_steamid.m_unAll64Bits))
+                    })
+                },
+            );
+        }
+    }
+
+    async_call_results! {
+        fn request_user_stats_async => request_user_stats(user: SteamId) -> Result<SteamId, SteamError>;
+        fn store_user_stats_async => store_user_stats(user: SteamId) -> Result<SteamId, SteamError>;
+    }
+}
+
+/// Called when the stats of a user have been unloaded from the server,
+/// usually because the user disconnected.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GSStatsUnloaded {
+    pub steam_id: SteamId,
+}
+
+unsafe impl Callback for GSStatsUnloaded {
+    const ID: i32 = 1108;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GSStatsUnloaded_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GSStatsUnloaded_t);
+        GSStatsUnloaded {
+            steam_id: SteamId(val.m_steamIDUser.m_steamid.m_unAll64Bits),
+        }
+    }
+}
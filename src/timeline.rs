@@ -1,6 +1,8 @@
 use super::*;
 use std::time::Duration;
 
+/// Access to the steam timeline interface, used to add markers to the
+/// timeline of Game Recording
 pub struct Timeline<Manager> {
     pub(crate) timeline: *mut sys::ISteamTimeline,
     /// Whether the client's steam API is not recent enough.
@@ -8,6 +10,8 @@ pub struct Timeline<Manager> {
     pub(crate) _inner: Arc<Inner<Manager>>,
 }
 
+/// The game mode shown on the timeline bar
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TimelineGameMode {
     /// The player is fully loaded into the game and playing.
     Playing,
@@ -32,6 +36,8 @@ impl From<TimelineGameMode> for sys::ETimelineGameMode {
     }
 }
 
+/// How likely the user is to want a clip of a timeline event
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TimelineEventClipPriority {
     /// This event is not appropriate as a clip.
     None,
@@ -106,6 +112,10 @@ impl<Manager> Timeline<Manager> {
     /// Use this to mark an event on the Timeline.
     /// The event can be instantaneous or take some amount of time to complete,
     /// depending on the value passed in `duration`.
+    ///
+    /// `icon` is the name of one of the timeline icons set up for the app or
+    /// one of the built in `steam_` icons. `start_offset_seconds` is relative
+    /// to the current time, so a negative value marks an event in the past.
     pub fn add_timeline_event(
         &self,
         icon: &str,
@@ -138,4 +148,27 @@ impl<Manager> Timeline<Manager> {
             )
         }
     }
+
+    /// Marks an instantaneous event on the Timeline, such as "Boss defeated".
+    ///
+    /// This is the same as calling `add_timeline_event` with a zero duration.
+    pub fn add_instantaneous_timeline_event(
+        &self,
+        icon: &str,
+        title: &str,
+        description: &str,
+        priority: u32,
+        start_offset_seconds: f32,
+        clip_priority: TimelineEventClipPriority,
+    ) {
+        self.add_timeline_event(
+            icon,
+            title,
+            description,
+            priority,
+            start_offset_seconds,
+            Duration::ZERO,
+            clip_priority,
+        )
+    }
 }
@@ -13,6 +13,7 @@ pub const RESULTS_PER_PAGE: u32 = sys::kNumUGCResultsPerPage as u32;
 pub struct UGC<Manager> {
     pub(crate) ugc: *mut sys::ISteamUGC,
     pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
 }
 
 const CALLBACK_BASE_ID: i32 = 3400;
@@ -34,6 +35,7 @@ impl From<u64> for PublishedFileId {
 
 /// Workshop item types to search for
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UGCType {
     Items,
     ItemsMtx,
@@ -160,6 +162,7 @@ impl Into<sys::EUGCQuery> for UGCQueryType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FileType {
     Community,
     Microtransaction,
@@ -412,6 +415,7 @@ impl Into<sys::EItemStatistic> for UGCStatisticType {
 }
 
 bitflags! {
+    /// The local state of a workshop item, as returned by `UGC::item_state`.
     pub struct ItemState: u32 {
         const NONE = 0;
         const SUBSCRIBED = 1;
@@ -483,6 +487,64 @@ impl From<sys::EUGCContentDescriptorID> for UGCContentDescriptorID {
     }
 }
 
+/// The type of an additional preview of a workshop item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UGCPreviewType {
+    /// A standard image file (e.g. jpg, png, gif)
+    Image,
+    /// A YouTube video id
+    YouTubeVideo,
+    /// A Sketchfab model id
+    Sketchfab,
+    /// A standard image file laid out as a horizontal cross environment map
+    EnvironmentMapHorizontalCross,
+    /// A standard image file laid out as a lat-long environment map
+    EnvironmentMapLatLong,
+    /// A clip id
+    Clip,
+    /// A preview type not known to this crate
+    Unknown,
+}
+impl TryFrom<UGCPreviewType> for sys::EItemPreviewType {
+    type Error = UnknownPreviewTypeError;
+
+    fn try_from(preview_type: UGCPreviewType) -> Result<Self, Self::Error> {
+        Ok(match preview_type {
+            UGCPreviewType::Image => sys::EItemPreviewType::k_EItemPreviewType_Image,
+            UGCPreviewType::YouTubeVideo => sys::EItemPreviewType::k_EItemPreviewType_YouTubeVideo,
+            UGCPreviewType::Sketchfab => sys::EItemPreviewType::k_EItemPreviewType_Sketchfab,
+            UGCPreviewType::EnvironmentMapHorizontalCross => {
+                sys::EItemPreviewType::k_EItemPreviewType_EnvironmentMap_HorizontalCross
+            }
+            UGCPreviewType::EnvironmentMapLatLong => {
+                sys::EItemPreviewType::k_EItemPreviewType_EnvironmentMap_LatLong
+            }
+            UGCPreviewType::Clip => sys::EItemPreviewType::k_EItemPreviewType_Clip,
+            UGCPreviewType::Unknown => return Err(UnknownPreviewTypeError),
+        })
+    }
+}
+impl From<sys::EItemPreviewType> for UGCPreviewType {
+    fn from(preview_type: sys::EItemPreviewType) -> UGCPreviewType {
+        match preview_type {
+            sys::EItemPreviewType::k_EItemPreviewType_Image => UGCPreviewType::Image,
+            sys::EItemPreviewType::k_EItemPreviewType_YouTubeVideo => UGCPreviewType::YouTubeVideo,
+            sys::EItemPreviewType::k_EItemPreviewType_Sketchfab => UGCPreviewType::Sketchfab,
+            sys::EItemPreviewType::k_EItemPreviewType_EnvironmentMap_HorizontalCross => {
+                UGCPreviewType::EnvironmentMapHorizontalCross
+            }
+            sys::EItemPreviewType::k_EItemPreviewType_EnvironmentMap_LatLong => {
+                UGCPreviewType::EnvironmentMapLatLong
+            }
+            sys::EItemPreviewType::k_EItemPreviewType_Clip => UGCPreviewType::Clip,
+            _ => UGCPreviewType::Unknown,
+        }
+    }
+}
+
+/// Called when a workshop item download started with `UGC::download_item`
+/// has finished.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DownloadItemResult {
@@ -509,11 +571,106 @@ unsafe impl Callback for DownloadItemResult {
     }
 }
 
+/// Called when a workshop item has been installed or updated.
+///
+/// The content of the item can be found through `UGC::item_install_info`
+/// once this has been received.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemInstalled {
+    pub app_id: AppId,
+    pub published_file_id: PublishedFileId,
+}
+
+unsafe impl Callback for ItemInstalled {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::ItemInstalled_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::ItemInstalled_t);
+        ItemInstalled {
+            app_id: AppId(val.m_unAppID),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+        }
+    }
+}
+
+/// Called when the user subscribes to a workshop item, including
+/// subscriptions made outside of the game (e.g. through the overlay).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RemoteStoragePublishedFileSubscribed {
+    pub app_id: AppId,
+    pub published_file_id: PublishedFileId,
+}
+
+unsafe impl Callback for RemoteStoragePublishedFileSubscribed {
+    const ID: i32 = CALLBACK_REMOTE_STORAGE_BASE_ID + 21;
+    const SIZE: i32 = ::std::mem::size_of::<sys::RemoteStoragePublishedFileSubscribed_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RemoteStoragePublishedFileSubscribed_t);
+        RemoteStoragePublishedFileSubscribed {
+            app_id: AppId(val.m_nAppID),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+        }
+    }
+}
+
+/// Called when the user unsubscribes from a workshop item, including
+/// unsubscriptions made outside of the game (e.g. through the overlay).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RemoteStoragePublishedFileUnsubscribed {
+    pub app_id: AppId,
+    pub published_file_id: PublishedFileId,
+}
+
+unsafe impl Callback for RemoteStoragePublishedFileUnsubscribed {
+    const ID: i32 = CALLBACK_REMOTE_STORAGE_BASE_ID + 22;
+    const SIZE: i32 = ::std::mem::size_of::<sys::RemoteStoragePublishedFileUnsubscribed_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RemoteStoragePublishedFileUnsubscribed_t);
+        RemoteStoragePublishedFileUnsubscribed {
+            app_id: AppId(val.m_nAppID),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+        }
+    }
+}
+
+/// The current user's vote on a workshop item
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UserItemVote {
+    /// The user voted the item up
+    pub voted_up: bool,
+    /// The user voted the item down
+    pub voted_down: bool,
+    /// The user chose to skip voting on the item
+    pub vote_skipped: bool,
+}
+
+/// The apps a workshop item depends on
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AppDependencies {
+    pub published_file_id: PublishedFileId,
+    /// The app ids returned by this call, at most 32
+    pub app_ids: Vec<AppId>,
+    /// The total number of app dependencies of the item
+    pub total_app_ids: u32,
+}
+
+/// Information about an installed workshop item
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InstallInfo {
+    /// The absolute path to the folder containing the item's content
     pub folder: String,
+    /// The size of the item on disk in bytes
     pub size_on_disk: u64,
+    /// The time the item was last updated in unix epoch seconds format
     pub timestamp: u32,
 }
 
@@ -534,11 +691,12 @@ impl<Manager> UGC<Manager> {
             let api_call = sys::SteamAPI_ISteamUGC_CreateItem(self.ugc, app_id.0, file_type.into());
             register_call_result::<sys::CreateItemResult_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 3,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else if v.m_eResult != sys::EResult::k_EResultOK {
                         Err(v.m_eResult.into())
                     } else {
@@ -564,6 +722,7 @@ impl<Manager> UGC<Manager> {
             UpdateHandle {
                 ugc: self.ugc,
                 inner: self.inner.clone(),
+                options: self.options.clone(),
 
                 handle,
             }
@@ -579,11 +738,12 @@ impl<Manager> UGC<Manager> {
             let api_call = sys::SteamAPI_ISteamUGC_SubscribeItem(self.ugc, published_file_id.0);
             register_call_result::<sys::RemoteStorageSubscribePublishedFileResult_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_REMOTE_STORAGE_BASE_ID + 13,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else if v.m_eResult != sys::EResult::k_EResultOK {
                         Err(v.m_eResult.into())
                     } else {
@@ -594,6 +754,9 @@ impl<Manager> UGC<Manager> {
         }
     }
 
+    /// Unsubscribes from a workshop item
+    ///
+    /// The item will be uninstalled once the game quits.
     pub fn unsubscribe_item<F>(&self, published_file_id: PublishedFileId, cb: F)
     where
         F: FnOnce(Result<(), SteamError>) + 'static + Send,
@@ -602,11 +765,12 @@ impl<Manager> UGC<Manager> {
             let api_call = sys::SteamAPI_ISteamUGC_UnsubscribeItem(self.ugc, published_file_id.0);
             register_call_result::<sys::RemoteStorageUnsubscribePublishedFileResult_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_REMOTE_STORAGE_BASE_ID + 15,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else if v.m_eResult != sys::EResult::k_EResultOK {
                         Err(v.m_eResult.into())
                     } else {
@@ -629,6 +793,7 @@ impl<Manager> UGC<Manager> {
         }
     }
 
+    /// Gets the current state of a workshop item on this client.
     pub fn item_state(&self, item: PublishedFileId) -> ItemState {
         unsafe {
             let state = sys::SteamAPI_ISteamUGC_GetItemState(self.ugc, item.0);
@@ -636,6 +801,11 @@ impl<Manager> UGC<Manager> {
         }
     }
 
+    /// Gets the download progress of a workshop item as
+    /// `(bytes downloaded, bytes total)`.
+    ///
+    /// Returns `None` if the item isn't downloading or the
+    /// download information isn't available yet.
     pub fn item_download_info(&self, item: PublishedFileId) -> Option<(u64, u64)> {
         unsafe {
             let mut current = 0u64;
@@ -653,6 +823,9 @@ impl<Manager> UGC<Manager> {
         }
     }
 
+    /// Gets info about an installed workshop item.
+    ///
+    /// Returns `None` if the item isn't installed.
     pub fn item_install_info(&self, item: PublishedFileId) -> Option<InstallInfo> {
         unsafe {
             let mut size_on_disk = 0u64;
@@ -679,6 +852,13 @@ impl<Manager> UGC<Manager> {
         }
     }
 
+    /// Downloads or updates a workshop item.
+    ///
+    /// If `high_priority` is set the item will be downloaded before any
+    /// other pending downloads. A `DownloadItemResult` callback will be
+    /// fired when the download has finished.
+    ///
+    /// Returns `false` if the item id is invalid or the user isn't logged on.
     pub fn download_item(&self, item: PublishedFileId, high_priority: bool) -> bool {
         unsafe { sys::SteamAPI_ISteamUGC_DownloadItem(self.ugc, item.0, high_priority) }
     }
@@ -712,6 +892,7 @@ impl<Manager> UGC<Manager> {
         Ok(QueryHandle {
             ugc: self.ugc,
             inner: Arc::clone(&self.inner),
+            options: self.options.clone(),
             handle: Some(handle),
         })
     }
@@ -746,6 +927,7 @@ impl<Manager> UGC<Manager> {
         Ok(QueryHandle {
             ugc: self.ugc,
             inner: Arc::clone(&self.inner),
+            options: self.options.clone(),
             handle: Some(res),
         })
     }
@@ -771,6 +953,7 @@ impl<Manager> UGC<Manager> {
         Ok(QueryHandle {
             ugc: self.ugc,
             inner: Arc::clone(&self.inner),
+            options: self.options.clone(),
             handle: Some(res),
         })
     }
@@ -796,10 +979,351 @@ impl<Manager> UGC<Manager> {
         Ok(QueryHandle {
             ugc: self.ugc,
             inner: Arc::clone(&self.inner),
+            options: self.options.clone(),
             handle: Some(res),
         })
     }
 
+    /// Votes an item up or down on behalf of the current user.
+    pub fn set_user_item_vote<F>(&self, published_file_id: PublishedFileId, vote_up: bool, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamUGC_SetUserItemVote(self.ugc, published_file_id.0, vote_up);
+            register_call_result::<sys::SetUserItemVoteResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 8,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Gets the current user's vote on an item.
+    pub fn get_user_item_vote<F>(&self, published_file_id: PublishedFileId, cb: F)
+    where
+        F: FnOnce(Result<UserItemVote, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_GetUserItemVote(self.ugc, published_file_id.0);
+            register_call_result::<sys::GetUserItemVoteResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 9,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(UserItemVote {
+                            voted_up: v.m_bVotedUp,
+                            voted_down: v.m_bVotedDown,
+                            vote_skipped: v.m_bVoteSkipped,
+                        })
+                    })
+                },
+            );
+        }
+    }
+
+    /// Adds a workshop item to the user's favorites list.
+    pub fn add_item_to_favorites<F>(&self, app_id: AppId, published_file_id: PublishedFileId, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamUGC_AddItemToFavorites(self.ugc, app_id.0, published_file_id.0);
+            register_call_result::<sys::UserFavoriteItemsListChanged_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 7,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Removes a workshop item from the user's favorites list.
+    pub fn remove_item_from_favorites<F>(
+        &self,
+        app_id: AppId,
+        published_file_id: PublishedFileId,
+        cb: F,
+    ) where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_RemoveItemFromFavorites(
+                self.ugc,
+                app_id.0,
+                published_file_id.0,
+            );
+            register_call_result::<sys::UserFavoriteItemsListChanged_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 7,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Starts tracking playtime on a set of workshop items.
+    ///
+    /// When your app shuts down, playtime tracking will automatically stop.
+    pub fn start_playtime_tracking<F>(&self, items: &[PublishedFileId], cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        let mut items: Vec<sys::PublishedFileId_t> = items.iter().map(|v| v.0).collect();
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_StartPlaytimeTracking(
+                self.ugc,
+                items.as_mut_ptr(),
+                items.len() as _,
+            );
+            register_call_result::<sys::StartPlaytimeTrackingResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 10,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Stops tracking playtime on a set of workshop items.
+    pub fn stop_playtime_tracking<F>(&self, items: &[PublishedFileId], cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        let mut items: Vec<sys::PublishedFileId_t> = items.iter().map(|v| v.0).collect();
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_StopPlaytimeTracking(
+                self.ugc,
+                items.as_mut_ptr(),
+                items.len() as _,
+            );
+            register_call_result::<sys::StopPlaytimeTrackingResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 11,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Stops tracking playtime of all workshop items.
+    pub fn stop_playtime_tracking_for_all_items<F>(&self, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_StopPlaytimeTrackingForAllItems(self.ugc);
+            register_call_result::<sys::StopPlaytimeTrackingResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 11,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Adds a workshop item as a dependency of another item.
+    pub fn add_dependency<F>(&self, parent: PublishedFileId, child: PublishedFileId, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_AddDependency(self.ugc, parent.0, child.0);
+            register_call_result::<sys::AddUGCDependencyResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 12,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Removes a workshop item dependency from another item.
+    pub fn remove_dependency<F>(&self, parent: PublishedFileId, child: PublishedFileId, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_RemoveDependency(self.ugc, parent.0, child.0);
+            register_call_result::<sys::RemoveUGCDependencyResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 13,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Adds an app (e.g. a DLC) as a dependency of a workshop item.
+    pub fn add_app_dependency<F>(&self, published_file_id: PublishedFileId, app_id: AppId, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamUGC_AddAppDependency(self.ugc, published_file_id.0, app_id.0);
+            register_call_result::<sys::AddAppDependencyResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 14,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Removes an app dependency from a workshop item.
+    pub fn remove_app_dependency<F>(&self, published_file_id: PublishedFileId, app_id: AppId, cb: F)
+    where
+        F: FnOnce(Result<(), SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUGC_RemoveAppDependency(
+                self.ugc,
+                published_file_id.0,
+                app_id.0,
+            );
+            register_call_result::<sys::RemoveAppDependencyResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 15,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Gets the apps a workshop item depends on.
+    ///
+    /// Dependencies on other workshop items can be found through
+    /// `QueryResults::get_children` instead.
+    pub fn get_app_dependencies<F>(&self, published_file_id: PublishedFileId, cb: F)
+    where
+        F: FnOnce(Result<AppDependencies, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamUGC_GetAppDependencies(self.ugc, published_file_id.0);
+            register_call_result::<sys::GetAppDependenciesResult_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                CALLBACK_BASE_ID + 16,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        let count = (v.m_nNumAppDependencies as usize).min(v.m_rgAppIDs.len());
+                        Ok(AppDependencies {
+                            published_file_id: PublishedFileId(v.m_nPublishedFileId),
+                            app_ids: v.m_rgAppIDs[..count].iter().map(|&id| AppId(id)).collect(),
+                            total_app_ids: v.m_nTotalNumAppDependencies,
+                        })
+                    })
+                },
+            );
+        }
+    }
+
     /// **DELETES** the item from the Steam Workshop.
     pub fn delete_item<F>(&self, published_file_id: PublishedFileId, cb: F)
     where
@@ -809,11 +1333,12 @@ impl<Manager> UGC<Manager> {
             let api_call = sys::SteamAPI_ISteamUGC_DeleteItem(self.ugc, published_file_id.0);
             register_call_result::<sys::DownloadItemResult_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_REMOTE_STORAGE_BASE_ID + 17,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else if v.m_eResult != sys::EResult::k_EResultNone
                         && v.m_eResult != sys::EResult::k_EResultOK
                     {
@@ -825,6 +1350,25 @@ impl<Manager> UGC<Manager> {
             );
         }
     }
+
+    async_call_results! {
+        fn create_item_async => create_item(app_id: AppId, file_type: FileType) -> Result<(PublishedFileId, bool), SteamError>;
+        fn subscribe_item_async => subscribe_item(published_file_id: PublishedFileId) -> Result<(), SteamError>;
+        fn unsubscribe_item_async => unsubscribe_item(published_file_id: PublishedFileId) -> Result<(), SteamError>;
+        fn set_user_item_vote_async => set_user_item_vote(published_file_id: PublishedFileId, vote_up: bool) -> Result<(), SteamError>;
+        fn get_user_item_vote_async => get_user_item_vote(published_file_id: PublishedFileId) -> Result<UserItemVote, SteamError>;
+        fn add_item_to_favorites_async => add_item_to_favorites(app_id: AppId, published_file_id: PublishedFileId) -> Result<(), SteamError>;
+        fn remove_item_from_favorites_async => remove_item_from_favorites(app_id: AppId, published_file_id: PublishedFileId) -> Result<(), SteamError>;
+        fn start_playtime_tracking_async => start_playtime_tracking(items: &[PublishedFileId]) -> Result<(), SteamError>;
+        fn stop_playtime_tracking_async => stop_playtime_tracking(items: &[PublishedFileId]) -> Result<(), SteamError>;
+        fn stop_playtime_tracking_for_all_items_async => stop_playtime_tracking_for_all_items() -> Result<(), SteamError>;
+        fn add_dependency_async => add_dependency(parent: PublishedFileId, child: PublishedFileId) -> Result<(), SteamError>;
+        fn remove_dependency_async => remove_dependency(parent: PublishedFileId, child: PublishedFileId) -> Result<(), SteamError>;
+        fn add_app_dependency_async => add_app_dependency(published_file_id: PublishedFileId, app_id: AppId) -> Result<(), SteamError>;
+        fn remove_app_dependency_async => remove_app_dependency(published_file_id: PublishedFileId, app_id: AppId) -> Result<(), SteamError>;
+        fn get_app_dependencies_async => get_app_dependencies(published_file_id: PublishedFileId) -> Result<AppDependencies, SteamError>;
+        fn delete_item_async => delete_item(published_file_id: PublishedFileId) -> Result<(), SteamError>;
+    }
 }
 
 impl UGC<ServerManager> {
@@ -851,6 +1395,7 @@ impl UGC<ServerManager> {
 pub struct UpdateHandle<Manager> {
     ugc: *mut sys::ISteamUGC,
     inner: Arc<Inner<Manager>>,
+    options: CallOptions,
 
     handle: sys::UGCUpdateHandle_t,
 }
@@ -1005,6 +1550,93 @@ impl<Manager> UpdateHandle<Manager> {
         self
     }
 
+    /// Adds an additional preview file for the item.
+    ///
+    /// `preview_type` should be an image based type, use `add_preview_video`
+    /// for YouTube videos.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `preview_type` is `UGCPreviewType::Unknown`, which can only
+    /// be returned by steam.
+    #[must_use]
+    pub fn add_preview_file(self, path: &Path, preview_type: UGCPreviewType) -> Self {
+        let preview_type = sys::EItemPreviewType::try_from(preview_type).unwrap();
+        unsafe {
+            let path = path.canonicalize().unwrap();
+            let preview_path = CString::new(&*path.to_string_lossy()).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_AddItemPreviewFile(
+                self.ugc,
+                self.handle,
+                preview_path.as_ptr(),
+                preview_type
+            ));
+        }
+        self
+    }
+
+    /// Adds an additional YouTube video preview for the item.
+    ///
+    /// `video_id` is the id of the video, e.g. `jHgZh4GV9G0`.
+    #[must_use]
+    pub fn add_preview_video(self, video_id: &str) -> Self {
+        unsafe {
+            let video_id = CString::new(video_id).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_AddItemPreviewVideo(
+                self.ugc,
+                self.handle,
+                video_id.as_ptr()
+            ));
+        }
+        self
+    }
+
+    /// Replaces the additional preview file at the given index.
+    ///
+    /// The index can be found with `QueryResults::num_additional_previews`.
+    #[must_use]
+    pub fn update_preview_file(self, index: u32, path: &Path) -> Self {
+        unsafe {
+            let path = path.canonicalize().unwrap();
+            let preview_path = CString::new(&*path.to_string_lossy()).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_UpdateItemPreviewFile(
+                self.ugc,
+                self.handle,
+                index,
+                preview_path.as_ptr()
+            ));
+        }
+        self
+    }
+
+    /// Replaces the additional YouTube video preview at the given index.
+    #[must_use]
+    pub fn update_preview_video(self, index: u32, video_id: &str) -> Self {
+        unsafe {
+            let video_id = CString::new(video_id).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_UpdateItemPreviewVideo(
+                self.ugc,
+                self.handle,
+                index,
+                video_id.as_ptr()
+            ));
+        }
+        self
+    }
+
+    /// Removes the additional preview at the given index.
+    #[must_use]
+    pub fn remove_preview(self, index: u32) -> Self {
+        unsafe {
+            assert!(sys::SteamAPI_ISteamUGC_RemoveItemPreview(
+                self.ugc,
+                self.handle,
+                index
+            ));
+        }
+        self
+    }
+
     pub fn submit<F>(self, change_note: Option<&str>, cb: F) -> UpdateWatchHandle<Manager>
     where
         F: FnOnce(Result<(PublishedFileId, bool), SteamError>) + 'static + Send,
@@ -1016,11 +1648,12 @@ impl<Manager> UpdateHandle<Manager> {
             let api_call = sys::SteamAPI_ISteamUGC_SubmitItemUpdate(self.ugc, self.handle, note);
             register_call_result::<sys::SubmitItemUpdateResult_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 4,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else if v.m_eResult != sys::EResult::k_EResultOK {
                         Err(v.m_eResult.into())
                     } else {
@@ -1100,6 +1733,7 @@ pub enum UpdateStatus {
 pub struct QueryHandle<Manager> {
     ugc: *mut sys::ISteamUGC,
     inner: Arc<Inner<Manager>>,
+    options: CallOptions,
 
     // Note: this is always filled except in `fetch`, where it must be taken
     // to prevent the handle from being dropped when this query is dropped.
@@ -1429,6 +2063,7 @@ impl<Manager> QueryHandle<Manager> {
     {
         let ugc = self.ugc;
         let inner = Arc::clone(&self.inner);
+        let options = self.options.clone();
         let handle = self.handle.take().unwrap();
         mem::drop(self);
 
@@ -1436,13 +2071,14 @@ impl<Manager> QueryHandle<Manager> {
             let api_call = sys::SteamAPI_ISteamUGC_SendQueryUGCRequest(ugc, handle);
             register_call_result::<sys::SteamUGCQueryCompleted_t, _, _>(
                 &inner,
+                &options,
                 api_call,
                 CALLBACK_BASE_ID + 1,
-                move |v, io_error| {
+                move |v, failure| {
                     let ugc = sys::SteamAPI_SteamUGC_v020();
-                    if io_error {
+                    if let Some(err) = failure {
                         sys::SteamAPI_ISteamUGC_ReleaseQueryUGCRequest(ugc, handle);
-                        cb(Err(SteamError::IOFailure));
+                        cb(Err(err));
                         return;
                     } else if v.m_eResult != sys::EResult::k_EResultOK {
                         sys::SteamAPI_ISteamUGC_ReleaseQueryUGCRequest(ugc, handle);
@@ -1467,7 +2103,7 @@ impl<Manager> QueryHandle<Manager> {
     /// Runs the query, only fetching the total number of results.
     pub fn fetch_total<F>(self, cb: F)
     where
-        F: Fn(Result<u32, SteamError>) + 'static + Send,
+        F: FnOnce(Result<u32, SteamError>) + 'static + Send,
     {
         unsafe {
             let ok =
@@ -1481,7 +2117,7 @@ impl<Manager> QueryHandle<Manager> {
     /// Runs the query, only fetching the IDs.
     pub fn fetch_ids<F>(self, cb: F)
     where
-        F: Fn(Result<Vec<PublishedFileId>, SteamError>) + 'static + Send,
+        F: FnOnce(Result<Vec<PublishedFileId>, SteamError>) + 'static + Send,
     {
         unsafe {
             let ok = sys::SteamAPI_ISteamUGC_SetReturnOnlyIDs(self.ugc, self.handle.unwrap(), true);
@@ -1496,6 +2132,18 @@ impl<Manager> QueryHandle<Manager> {
             }))
         })
     }
+
+    /// Async version of [`fetch_total`](#method.fetch_total).
+    #[cfg(feature = "async")]
+    pub fn fetch_total_async(self) -> CallResultFuture<Result<u32, SteamError>> {
+        call_result_future(|c| self.fetch_total(move |v| c.complete(v)))
+    }
+
+    /// Async version of [`fetch_ids`](#method.fetch_ids).
+    #[cfg(feature = "async")]
+    pub fn fetch_ids_async(self) -> CallResultFuture<Result<Vec<PublishedFileId>, SteamError>> {
+        call_result_future(|c| self.fetch_ids(move |v| c.complete(v)))
+    }
 }
 
 /// Query results
@@ -1555,6 +2203,54 @@ impl<'a> QueryResults<'a> {
         }
     }
 
+    /// Returns the number of additional previews of the item at the specified index.
+    ///
+    /// You must call `include_additional_previews(true)` before fetching the query for this to work.
+    pub fn num_additional_previews(&self, index: u32) -> u32 {
+        unsafe {
+            sys::SteamAPI_ISteamUGC_GetQueryUGCNumAdditionalPreviews(self.ugc, self.handle, index)
+        }
+    }
+
+    /// Gets an additional preview of the item at the specified index.
+    ///
+    /// Returns None if either index was out of bounds.
+    pub fn additional_preview(&self, index: u32, preview_index: u32) -> Option<AdditionalPreview> {
+        let mut url = [0 as c_char; 4096];
+        let mut file_name = [0 as c_char; 4096];
+        let mut preview_type = sys::EItemPreviewType::k_EItemPreviewType_Image;
+
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_GetQueryUGCAdditionalPreview(
+                self.ugc,
+                self.handle,
+                index,
+                preview_index,
+                url.as_mut_ptr(),
+                url.len() as _,
+                file_name.as_mut_ptr(),
+                file_name.len() as _,
+                &mut preview_type,
+            )
+        };
+
+        if ok {
+            Some(unsafe {
+                AdditionalPreview {
+                    url_or_video_id: CStr::from_ptr(url.as_ptr() as *const _)
+                        .to_string_lossy()
+                        .into_owned(),
+                    original_file_name: CStr::from_ptr(file_name.as_ptr() as *const _)
+                        .to_string_lossy()
+                        .into_owned(),
+                    preview_type: preview_type.into(),
+                }
+            })
+        } else {
+            None
+        }
+    }
+
     /// Gets a UGC statistic about the published file at the specified index.
     pub fn statistic(&self, index: u32, stat_type: UGCStatisticType) -> Option<u64> {
         let mut value = 0u64;
@@ -1771,6 +2467,7 @@ impl<'a> QueryResults<'a> {
 
 /// Query result
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct QueryResult {
     pub published_file_id: PublishedFileId,
     pub creator_app_id: Option<AppId>,
@@ -1803,6 +2500,28 @@ pub struct QueryResult {
     // TODO: Add missing fields as needed
 }
 
+/// An additional preview of a workshop item
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdditionalPreview {
+    /// The URL of the image, or the id of the video for video previews
+    pub url_or_video_id: String,
+    /// The original file name of the preview, if it was an uploaded file
+    pub original_file_name: String,
+    pub preview_type: UGCPreviewType,
+}
+
+/// Returned when converting `UGCPreviewType::Unknown` into a preview type
+/// for steam
+#[derive(Debug, Clone, Copy)]
+pub struct UnknownPreviewTypeError;
+impl fmt::Display for UnknownPreviewTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown preview types can not be passed to steam")
+    }
+}
+impl error::Error for UnknownPreviewTypeError {}
+
 #[derive(Debug, Clone, Copy)]
 pub struct CreateQueryError;
 impl fmt::Display for CreateQueryError {
@@ -6,7 +6,8 @@ use serial_test::serial;
 /// Access to the steam user interface
 pub struct User<Manager> {
     pub(crate) user: *mut sys::ISteamUser,
-    pub(crate) _inner: Arc<Inner<Manager>>,
+    pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
 }
 
 impl<Manager> User<Manager> {
@@ -20,11 +21,284 @@ impl<Manager> User<Manager> {
         unsafe { sys::SteamAPI_ISteamUser_GetPlayerSteamLevel(self.user) as u32 }
     }
 
+    /// Returns the level of the current user's badge for the current
+    /// game.
+    ///
+    /// `series` is the badge series, starting at 1. If `foil` is set the
+    /// level of the foil badge is returned instead.
+    pub fn game_badge_level(&self, series: i32, foil: bool) -> u32 {
+        unsafe { sys::SteamAPI_ISteamUser_GetGameBadgeLevel(self.user, series, foil).max(0) as u32 }
+    }
+
     /// Returns whether the current user's Steam client is connected to the Steam servers.
+    ///
+    /// Changes in the connection state are reported through the
+    /// `SteamServersConnected`, `SteamServersDisconnected` and
+    /// `SteamServerConnectFailure` callbacks.
     pub fn logged_on(&self) -> bool {
         unsafe { sys::SteamAPI_ISteamUser_BLoggedOn(self.user) }
     }
 
+    /// Returns whether the current user appears to be behind a NAT
+    /// device.
+    ///
+    /// This is only valid once the user has connected to the Steam
+    /// servers and may not catch all forms of NAT.
+    pub fn is_behind_nat(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamUser_BIsBehindNAT(self.user) }
+    }
+
+    /// Starts voice recording.
+    ///
+    /// Once started, use `voice` to get the recorded data. Recording
+    /// continues briefly after `stop_voice_recording` is called so
+    /// `voice` should be called until it stops returning data.
+    pub fn start_voice_recording(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamUser_StartVoiceRecording(self.user);
+        }
+    }
+
+    /// Stops voice recording.
+    pub fn stop_voice_recording(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamUser_StopVoiceRecording(self.user);
+        }
+    }
+
+    /// Returns the number of bytes of compressed voice data that are
+    /// currently available.
+    pub fn available_voice(&self) -> Result<u32, VoiceError> {
+        unsafe {
+            let mut compressed = 0;
+            let res = sys::SteamAPI_ISteamUser_GetAvailableVoice(
+                self.user,
+                &mut compressed,
+                std::ptr::null_mut(),
+                0,
+            );
+            match voice_result(res) {
+                Ok(()) | Err(VoiceError::NoData) => Ok(compressed),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
+    /// Reads the currently available compressed voice data.
+    ///
+    /// Returns an empty buffer if there is no new data. This should be
+    /// called every frame while recording to keep latency low. The data
+    /// can be sent to other users and decoded with `decompress_voice`.
+    pub fn voice(&self) -> Result<Vec<u8>, VoiceError> {
+        let available = self.available_voice()?;
+        if available == 0 {
+            return Ok(Vec::new());
+        }
+        unsafe {
+            let mut buffer = vec![0u8; available as usize];
+            let mut written = 0;
+            let res = sys::SteamAPI_ISteamUser_GetVoice(
+                self.user,
+                true,
+                buffer.as_mut_ptr() as *mut _,
+                buffer.len() as u32,
+                &mut written,
+                false,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                0,
+            );
+            match voice_result(res) {
+                Ok(()) => {
+                    buffer.truncate(written as usize);
+                    Ok(buffer)
+                }
+                Err(VoiceError::NoData) => Ok(Vec::new()),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
+    /// Returns the sample rate the Steam voice decompressor runs at
+    /// natively.
+    ///
+    /// Decompressing at this rate avoids resampling.
+    pub fn voice_optimal_sample_rate(&self) -> u32 {
+        unsafe { sys::SteamAPI_ISteamUser_GetVoiceOptimalSampleRate(self.user) }
+    }
+
+    /// Decompresses voice data returned by `voice` into signed 16-bit
+    /// mono PCM samples.
+    ///
+    /// `sample_rate` is the desired output rate, between 11025 and
+    /// 48000. If `None` the `voice_optimal_sample_rate` is used.
+    pub fn decompress_voice(
+        &self,
+        compressed: &[u8],
+        sample_rate: Option<u32>,
+    ) -> Result<Vec<i16>, VoiceError> {
+        let sample_rate = self.voice_sample_rate(sample_rate)?;
+        let mut buffer = Vec::new();
+        self.decompress_voice_into(compressed, sample_rate, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Returns an iterator decompressing each packet of voice data
+    /// returned by `voice` into a frame of signed 16-bit mono PCM
+    /// samples.
+    ///
+    /// `sample_rate` is the desired output rate, between 11025 and
+    /// 48000. If `None` the `voice_optimal_sample_rate` is used. The
+    /// frames can be fed directly into a mixer running at that rate.
+    ///
+    /// ```no_run
+    /// # let client = steamworks::Client::init().unwrap();
+    /// # let packets: Vec<Vec<u8>> = Vec::new();
+    /// let user = client.user();
+    /// for frame in user.decompress_voice_frames(&packets, None).unwrap() {
+    ///     let samples = frame.unwrap();
+    ///     // ... mix `samples`
+    /// }
+    /// ```
+    pub fn decompress_voice_frames<I>(
+        &self,
+        packets: I,
+        sample_rate: Option<u32>,
+    ) -> Result<VoiceFrames<'_, Manager, I::IntoIter>, VoiceError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let sample_rate = self.voice_sample_rate(sample_rate)?;
+        Ok(VoiceFrames {
+            user: self,
+            packets: packets.into_iter(),
+            sample_rate,
+        })
+    }
+
+    fn voice_sample_rate(&self, sample_rate: Option<u32>) -> Result<u32, VoiceError> {
+        let sample_rate = sample_rate.unwrap_or_else(|| self.voice_optimal_sample_rate());
+        if VOICE_SAMPLE_RATES.contains(&sample_rate) {
+            Ok(sample_rate)
+        } else {
+            Err(VoiceError::InvalidSampleRate(sample_rate))
+        }
+    }
+
+    fn decompress_voice_into(
+        &self,
+        compressed: &[u8],
+        sample_rate: u32,
+        buffer: &mut Vec<i16>,
+    ) -> Result<(), VoiceError> {
+        // Roughly one second of audio, grown below if Steam needs more
+        buffer.clear();
+        buffer.resize(sample_rate as usize, 0);
+        unsafe {
+            loop {
+                let mut written = 0;
+                let res = sys::SteamAPI_ISteamUser_DecompressVoice(
+                    self.user,
+                    compressed.as_ptr() as *const _,
+                    compressed.len() as u32,
+                    buffer.as_mut_ptr() as *mut _,
+                    (buffer.len() * 2) as u32,
+                    &mut written,
+                    sample_rate,
+                );
+                match voice_result(res) {
+                    Ok(()) => {
+                        buffer.truncate(written as usize / 2);
+                        return Ok(());
+                    }
+                    Err(VoiceError::BufferTooSmall) if written as usize > buffer.len() * 2 => {
+                        buffer.resize((written as usize + 1) / 2, 0);
+                    }
+                    Err(err) => {
+                        buffer.clear();
+                        return Err(err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Asynchronously checks whether the user is allowed to use the
+    /// Steam Community Market.
+    pub fn market_eligibility<F>(&self, cb: F)
+    where
+        F: FnOnce(Result<MarketEligibility, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUser_GetMarketEligibility(self.user);
+            register_call_result::<sys::MarketEligibilityResponse_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                166,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else {
+                        // The reasons are a set of flags so read them as
+                        // an integer rather than as an enum
+                        let reasons = *(std::ptr::addr_of!(v.m_eNotAllowedReason) as *const u32);
+                        Ok(MarketEligibility {
+                            allowed: v.m_bAllowed,
+                            not_allowed_reasons: MarketNotAllowedReasons::from_bits_truncate(
+                                reasons,
+                            ),
+                            allowed_at_time: v.m_rtAllowedAtTime,
+                            steam_guard_required_days: v.m_cdaySteamGuardRequiredDays,
+                            new_device_cooldown_days: v.m_cdayNewDeviceCooldown,
+                        })
+                    })
+                },
+            );
+        }
+    }
+
+    /// Asynchronously retrieves the user's current playtime limits for
+    /// this game.
+    ///
+    /// This is only relevant in regions with anti-addiction
+    /// regulations. The `DurationControl` callback is also posted
+    /// whenever the limits change.
+    pub fn duration_control<F>(&self, cb: F)
+    where
+        F: FnOnce(Result<DurationControl, SteamError>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUser_GetDurationControl(self.user);
+            register_call_result::<sys::DurationControl_t, _, _>(
+                &self.inner,
+                &self.options,
+                api_call,
+                167,
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(DurationControl::from_sys(v))
+                    })
+                },
+            );
+        }
+    }
+
+    /// Tells Steam whether the game is currently in an online or
+    /// offline state, so that playtime limits can be applied
+    /// appropriately.
+    ///
+    /// Returns false if the state could not be set.
+    pub fn set_duration_control_online_state(&self, state: DurationControlOnlineState) -> bool {
+        unsafe { sys::SteamAPI_ISteamUser_BSetDurationControlOnlineState(self.user, state.into()) }
+    }
+
     /// Retrieve an authentication session ticket that can be sent
     /// to an entity that wishes to verify you.
     ///
@@ -34,6 +308,9 @@ impl<Manager> User<Manager> {
     /// for the `AuthSessionTicketResponse` event before trying to
     /// use the ticket.
     ///
+    /// The ticket is bound to the given steam id, which should be the
+    /// id of the server or peer that will validate it.
+    ///
     /// When the multiplayer session terminates you must call
     /// `cancel_authentication_ticket`
     pub fn authentication_session_ticket_with_steam_id(
@@ -42,6 +319,11 @@ impl<Manager> User<Manager> {
     ) -> (AuthTicket, Vec<u8>) {
         self.authentication_session_ticket(NetworkingIdentity::new_steam_id(steam_id))
     }
+
+    /// Retrieve an authentication session ticket bound to the given
+    /// network identity.
+    ///
+    /// See `authentication_session_ticket_with_steam_id`.
     pub fn authentication_session_ticket(
         &self,
         network_identity: NetworkingIdentity,
@@ -130,9 +412,10 @@ impl<Manager> User<Manager> {
     /// ISteamUserAuth/AuthenticateUserTicket Web API.
     ///
     /// The calling application must wait for the
-    /// `TicketForWebApiResponse` callback generated  
-    /// by the API call to access the ticket.
-    ///  
+    /// `TicketForWebApiResponse` callback generated
+    /// by the API call to access the ticket. The ticket can be
+    /// passed to the Web API with `TicketForWebApiResponse::ticket_hex`.
+    ///
     /// It is best practice to use an identity string for
     /// each service that will consume tickets.
     ///   
@@ -150,6 +433,322 @@ impl<Manager> User<Manager> {
             AuthTicket(auth_ticket)
         }
     }
+
+    async_call_results! {
+        fn market_eligibility_async => market_eligibility() -> Result<MarketEligibility, SteamError>;
+        fn duration_control_async => duration_control() -> Result<DurationControl, SteamError>;
+    }
+}
+
+bitflags! {
+    /// Reasons a user may not use the Steam Community Market
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    pub struct MarketNotAllowedReasons: u32 {
+        const TEMPORARY_FAILURE                     = 0x0001;
+        const ACCOUNT_DISABLED                      = 0x0002;
+        const ACCOUNT_LOCKED_DOWN                   = 0x0004;
+        const ACCOUNT_LIMITED                       = 0x0008;
+        const TRADE_BANNED                          = 0x0010;
+        const ACCOUNT_NOT_TRUSTED                   = 0x0020;
+        const STEAM_GUARD_NOT_ENABLED               = 0x0040;
+        const STEAM_GUARD_ONLY_RECENTLY_ENABLED     = 0x0080;
+        const RECENT_PASSWORD_RESET                 = 0x0100;
+        const NEW_PAYMENT_METHOD                    = 0x0200;
+        const INVALID_COOKIE                        = 0x0400;
+        const USING_NEW_DEVICE                      = 0x0800;
+        const RECENT_SELF_REFUND                    = 0x1000;
+        const NEW_PAYMENT_METHOD_CANNOT_BE_VERIFIED = 0x2000;
+        const NO_RECENT_PURCHASES                   = 0x4000;
+        const ACCEPTED_WALLET_GIFT                  = 0x8000;
+    }
+}
+
+/// The result of `User::market_eligibility`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MarketEligibility {
+    /// Whether the user can use the market
+    pub allowed: bool,
+    /// Why the user can't use the market, if they can't
+    pub not_allowed_reasons: MarketNotAllowedReasons,
+    /// When the user will be allowed to use the market, as a unix
+    /// timestamp
+    pub allowed_at_time: u32,
+    /// The number of days Steam Guard must be enabled before trades
+    /// are not held
+    pub steam_guard_required_days: i32,
+    /// The number of days a new device must be used before trades are
+    /// not held
+    pub new_device_cooldown_days: i32,
+}
+
+/// The user's playtime limits for the current game. Posted in response
+/// to `User::duration_control` and whenever the limits change.
+#[derive(Clone, Debug)]
+pub struct DurationControl {
+    pub result: SResult<()>,
+    pub app_id: AppId,
+    /// Whether playtime limits apply to the user
+    pub applicable: bool,
+    /// Playtime in the last five hours, in seconds
+    pub seconds_last_5h: i32,
+    /// How much progress the user should receive in game
+    pub progress: DurationControlProgress,
+    /// A notification that should be shown to the user
+    pub notification: DurationControlNotification,
+    /// Playtime today, in seconds
+    pub seconds_today: i32,
+    /// Playtime remaining until the user must exit, in seconds
+    pub seconds_remaining: i32,
+}
+
+impl DurationControl {
+    fn from_sys(val: &sys::DurationControl_t) -> Self {
+        DurationControl {
+            result: if val.m_eResult == sys::EResult::k_EResultOK {
+                Ok(())
+            } else {
+                Err(val.m_eResult.into())
+            },
+            app_id: AppId(val.m_appid),
+            applicable: val.m_bApplicable,
+            seconds_last_5h: val.m_csecsLast5h,
+            progress: val.m_progress.into(),
+            notification: val.m_notification.into(),
+            seconds_today: val.m_csecsToday,
+            seconds_remaining: val.m_csecsRemaining,
+        }
+    }
+}
+
+unsafe impl Callback for DurationControl {
+    const ID: i32 = 167;
+    const SIZE: i32 = ::std::mem::size_of::<sys::DurationControl_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        DurationControl::from_sys(&*(raw as *mut sys::DurationControl_t))
+    }
+}
+
+/// How much in game progress a user subject to playtime limits should
+/// receive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DurationControlProgress {
+    /// Full progress
+    Full,
+    /// Progress should be halved
+    Half,
+    /// No progress should be given
+    None,
+    /// The user must exit soon because they played 3 hours today
+    ExitSoon3h,
+    /// The user must exit soon because they played 5 hours today
+    ExitSoon5h,
+    /// The user must exit soon because of the night time curfew
+    ExitSoonNight,
+}
+
+impl From<sys::EDurationControlProgress> for DurationControlProgress {
+    fn from(progress: sys::EDurationControlProgress) -> Self {
+        match progress {
+            sys::EDurationControlProgress::k_EDurationControlProgress_Half => {
+                DurationControlProgress::Half
+            }
+            sys::EDurationControlProgress::k_EDurationControlProgress_None => {
+                DurationControlProgress::None
+            }
+            sys::EDurationControlProgress::k_EDurationControl_ExitSoon_3h => {
+                DurationControlProgress::ExitSoon3h
+            }
+            sys::EDurationControlProgress::k_EDurationControl_ExitSoon_5h => {
+                DurationControlProgress::ExitSoon5h
+            }
+            sys::EDurationControlProgress::k_EDurationControl_ExitSoon_Night => {
+                DurationControlProgress::ExitSoonNight
+            }
+            _ => DurationControlProgress::Full,
+        }
+    }
+}
+
+/// A notification that should be shown to a user subject to playtime
+/// limits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DurationControlNotification {
+    /// Nothing needs to be shown
+    None,
+    /// The user has played for an hour
+    OneHour,
+    /// The user has played for three hours
+    ThreeHours,
+    /// The user now receives half progress
+    HalfProgress,
+    /// The user now receives no progress
+    NoProgress,
+    /// The user must exit soon because they played 3 hours today
+    ExitSoon3h,
+    /// The user must exit soon because they played 5 hours today
+    ExitSoon5h,
+    /// The user must exit soon because of the night time curfew
+    ExitSoonNight,
+}
+
+impl From<sys::EDurationControlNotification> for DurationControlNotification {
+    fn from(notification: sys::EDurationControlNotification) -> Self {
+        match notification {
+            sys::EDurationControlNotification::k_EDurationControlNotification_1Hour => {
+                DurationControlNotification::OneHour
+            }
+            sys::EDurationControlNotification::k_EDurationControlNotification_3Hours => {
+                DurationControlNotification::ThreeHours
+            }
+            sys::EDurationControlNotification::k_EDurationControlNotification_HalfProgress => {
+                DurationControlNotification::HalfProgress
+            }
+            sys::EDurationControlNotification::k_EDurationControlNotification_NoProgress => {
+                DurationControlNotification::NoProgress
+            }
+            sys::EDurationControlNotification::k_EDurationControlNotification_ExitSoon_3h => {
+                DurationControlNotification::ExitSoon3h
+            }
+            sys::EDurationControlNotification::k_EDurationControlNotification_ExitSoon_5h => {
+                DurationControlNotification::ExitSoon5h
+            }
+            sys::EDurationControlNotification::k_EDurationControlNotification_ExitSoon_Night => {
+                DurationControlNotification::ExitSoonNight
+            }
+            _ => DurationControlNotification::None,
+        }
+    }
+}
+
+/// The online state of the game, as passed to
+/// `User::set_duration_control_online_state`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DurationControlOnlineState {
+    /// The game is in single player or offline mode
+    Offline,
+    /// The game is in multiplayer mode and can be exited at any time
+    Online,
+    /// The game is in a match or other activity that shouldn't be
+    /// interrupted
+    OnlineHighPriority,
+}
+
+impl From<DurationControlOnlineState> for sys::EDurationControlOnlineState {
+    fn from(state: DurationControlOnlineState) -> Self {
+        match state {
+            DurationControlOnlineState::Offline => {
+                sys::EDurationControlOnlineState::k_EDurationControlOnlineState_Offline
+            }
+            DurationControlOnlineState::Online => {
+                sys::EDurationControlOnlineState::k_EDurationControlOnlineState_Online
+            }
+            DurationControlOnlineState::OnlineHighPriority => {
+                sys::EDurationControlOnlineState::k_EDurationControlOnlineState_OnlineHighPri
+            }
+        }
+    }
+}
+
+/// The sample rates accepted by the voice decompressor
+const VOICE_SAMPLE_RATES: std::ops::RangeInclusive<u32> = 11025..=48000;
+
+/// An iterator of decompressed voice frames, returned by
+/// `User::decompress_voice_frames`
+///
+/// Each item is the PCM samples of one packet of compressed voice data.
+pub struct VoiceFrames<'a, Manager, I> {
+    user: &'a User<Manager>,
+    packets: I,
+    sample_rate: u32,
+}
+
+impl<Manager, I> VoiceFrames<'_, Manager, I> {
+    /// Returns the sample rate of the decompressed frames
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+impl<Manager, I> Iterator for VoiceFrames<'_, Manager, I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = Result<Vec<i16>, VoiceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let packet = self.packets.next()?;
+        let mut buffer = Vec::new();
+        Some(
+            self.user
+                .decompress_voice_into(packet.as_ref(), self.sample_rate, &mut buffer)
+                .map(|()| buffer),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.packets.size_hint()
+    }
+}
+
+/// Errors from the voice recording functions
+#[derive(Clone, Debug, Error)]
+pub enum VoiceError {
+    /// The Steam voice subsystem is not initialized
+    #[error("voice not initialized")]
+    NotInitialized,
+    /// Voice recording is not active
+    #[error("voice recording is not active")]
+    NotRecording,
+    /// There is no voice data available
+    #[error("no voice data available")]
+    NoData,
+    /// The buffer was too small to hold the data
+    #[error("buffer too small")]
+    BufferTooSmall,
+    /// The voice data is corrupted
+    #[error("voice data corrupted")]
+    DataCorrupted,
+    /// The user is restricted from using voice chat
+    #[error("voice chat is restricted")]
+    Restricted,
+    /// The voice data uses a codec that is not supported
+    #[error("unsupported voice codec")]
+    UnsupportedCodec,
+    /// The receiver is running an outdated version of Steam
+    #[error("receiver is out of date")]
+    ReceiverOutOfDate,
+    /// The receiver did not answer
+    #[error("receiver did not answer")]
+    ReceiverDidNotAnswer,
+    /// The sample rate is outside of the supported 11025 to 48000 range
+    #[error("unsupported sample rate {0}")]
+    InvalidSampleRate(u32),
+    /// Steam returned a result code this crate doesn't know about
+    #[error("unknown voice result {0}")]
+    Unknown(i32),
+}
+
+fn voice_result(res: sys::EVoiceResult) -> Result<(), VoiceError> {
+    Err(match res {
+        sys::EVoiceResult::k_EVoiceResultOK => return Ok(()),
+        sys::EVoiceResult::k_EVoiceResultNotInitialized => VoiceError::NotInitialized,
+        sys::EVoiceResult::k_EVoiceResultNotRecording => VoiceError::NotRecording,
+        sys::EVoiceResult::k_EVoiceResultNoData => VoiceError::NoData,
+        sys::EVoiceResult::k_EVoiceResultBufferTooSmall => VoiceError::BufferTooSmall,
+        sys::EVoiceResult::k_EVoiceResultDataCorrupted => VoiceError::DataCorrupted,
+        sys::EVoiceResult::k_EVoiceResultRestricted => VoiceError::Restricted,
+        sys::EVoiceResult::k_EVoiceResultUnsupportedCodec => VoiceError::UnsupportedCodec,
+        sys::EVoiceResult::k_EVoiceResultReceiverOutOfDate => VoiceError::ReceiverOutOfDate,
+        sys::EVoiceResult::k_EVoiceResultReceiverDidNotAnswer => VoiceError::ReceiverDidNotAnswer,
+        _ => VoiceError::Unknown(res as i32),
+    })
 }
 
 /// Errors from `begin_authentication_session`
@@ -250,7 +849,8 @@ fn test_auth_webapi() {
     let user = client.user();
 
     let _cb = client.register_callback(|v: TicketForWebApiResponse| {
-        println!("Got webapi auth response: {:?}", v)
+        println!("Got webapi auth response: {:?}", v);
+        println!("Hex ticket: {}", v.ticket_hex());
     });
 
     let auth = user.authentication_session_ticket_for_webapi("myIdentity");
@@ -276,14 +876,21 @@ pub struct TicketForWebApiResponse {
     pub ticket: Vec<u8>,
 }
 
+impl TicketForWebApiResponse {
+    /// Returns the ticket hex-encoded, as expected by the
+    /// ISteamUserAuth/AuthenticateUserTicket Web API.
+    pub fn ticket_hex(&self) -> String {
+        self.ticket.iter().map(|b| format!("{:02X}", b)).collect()
+    }
+}
+
 unsafe impl Callback for TicketForWebApiResponse {
     const ID: i32 = 168;
     const SIZE: i32 = ::std::mem::size_of::<sys::GetTicketForWebApiResponse_t>() as i32;
 
     unsafe fn from_raw(raw: *mut c_void) -> Self {
-        println!("From raw: {:?}", raw);
-
         let val = &mut *(raw as *mut sys::GetTicketForWebApiResponse_t);
+        let ticket_len = (val.m_cubTicket.max(0) as usize).min(val.m_rgubTicket.len());
         TicketForWebApiResponse {
             ticket_handle: AuthTicket(val.m_hAuthTicket),
             result: if val.m_eResult == sys::EResult::k_EResultOK {
@@ -292,7 +899,7 @@ unsafe impl Callback for TicketForWebApiResponse {
                 Err(val.m_eResult.into())
             },
             ticket_len: val.m_cubTicket,
-            ticket: val.m_rgubTicket.to_vec(),
+            ticket: val.m_rgubTicket[..ticket_len].to_vec(),
         }
     }
 }
@@ -348,7 +955,10 @@ unsafe impl Callback for ValidateAuthTicketResponse {
                 sys::EAuthSessionResponse::k_EAuthSessionResponsePublisherIssuedBan => {
                     Err(AuthSessionValidateError::PublisherIssuedBan)
                 }
-                _ => unreachable!(),
+                sys::EAuthSessionResponse::k_EAuthSessionResponseAuthTicketNetworkIdentityFailure => {
+                    Err(AuthSessionValidateError::AuthTicketNetworkIdentityFailure)
+                }
+                _ => Err(AuthSessionValidateError::Unknown),
             },
         }
     }
@@ -378,6 +988,9 @@ unsafe impl Callback for MicroTxnAuthorizationResponse {
 }
 
 /// Called when a connection to the Steam servers is made.
+///
+/// For game servers this is sent once `Server::log_on` or
+/// `Server::log_on_anonymous` has succeeded.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SteamServersConnected;
@@ -467,4 +1080,12 @@ pub enum AuthSessionValidateError {
     /// The user is banned from the game (not VAC)
     #[error("the user is banned")]
     PublisherIssuedBan,
+    /// The network identity in the ticket does not match the server
+    /// authenticating the ticket
+    #[error("the authentication ticket was issued for a different identity")]
+    AuthTicketNetworkIdentityFailure,
+    /// The ticket was rejected for a reason not known to this version
+    /// of the library
+    #[error("the authentication ticket was rejected for an unknown reason")]
+    Unknown,
 }
@@ -10,6 +10,7 @@ use serial_test::serial;
 pub struct UserStats<Manager> {
     pub(crate) user_stats: *mut sys::ISteamUserStats,
     pub(crate) inner: Arc<Inner<Manager>>,
+    pub(crate) options: CallOptions,
 }
 
 const CALLBACK_BASE_ID: i32 = 1100;
@@ -27,11 +28,12 @@ impl<Manager> UserStats<Manager> {
             );
             register_call_result::<sys::LeaderboardFindResult_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 4,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else {
                         Ok(if v.m_bLeaderboardFound != 0 {
                             Some(Leaderboard(v.m_hSteamLeaderboard))
@@ -85,11 +87,12 @@ impl<Manager> UserStats<Manager> {
             );
             register_call_result::<sys::LeaderboardFindResult_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 4,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else {
                         Ok(if v.m_bLeaderboardFound != 0 {
                             Some(Leaderboard(v.m_hSteamLeaderboard))
@@ -131,11 +134,12 @@ impl<Manager> UserStats<Manager> {
             );
             register_call_result::<sys::LeaderboardScoreUploaded_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 6,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else {
                         Ok(if v.m_bSuccess != 0 {
                             Some(LeaderboardScoreUploaded {
@@ -186,11 +190,12 @@ impl<Manager> UserStats<Manager> {
             let user_stats = self.user_stats as isize;
             register_call_result::<sys::LeaderboardScoresDownloaded_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 CALLBACK_BASE_ID + 5,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else {
                         let len = v.m_cEntryCount;
                         let mut entries = Vec::with_capacity(len as usize);
@@ -310,13 +315,14 @@ impl<Manager> UserStats<Manager> {
                 sys::SteamAPI_ISteamUserStats_RequestGlobalAchievementPercentages(self.user_stats);
             register_call_result::<sys::GlobalAchievementPercentagesReady_t, _, _>(
                 &self.inner,
+                &self.options,
                 api_call,
                 // `CALLBACK_BASE_ID + <number>`: <number> is found in Steamworks `isteamuserstats.h` header file
                 // (Under `struct GlobalAchievementPercentagesReady_t {...};` in this case)
                 CALLBACK_BASE_ID + 10,
-                move |v, io_error| {
-                    cb(if io_error {
-                        Err(SteamError::IOFailure)
+                move |v, failure| {
+                    cb(if let Some(err) = failure {
+                        Err(err)
                     } else {
                         Ok(GameId(v.m_nGameID))
                     })
@@ -506,6 +512,14 @@ impl<Manager> UserStats<Manager> {
         }
         Some(names)
     }
+
+    async_call_results! {
+        fn find_leaderboard_async => find_leaderboard(name: &str) -> Result<Option<Leaderboard>, SteamError>;
+        fn find_or_create_leaderboard_async => find_or_create_leaderboard(name: &str, sort_method: LeaderboardSortMethod, display_type: LeaderboardDisplayType) -> Result<Option<Leaderboard>, SteamError>;
+        fn upload_leaderboard_score_async => upload_leaderboard_score(leaderboard: &Leaderboard, method: UploadScoreMethod, score: i32, details: &[i32]) -> Result<Option<LeaderboardScoreUploaded>, SteamError>;
+        fn download_leaderboard_entries_async => download_leaderboard_entries(leaderboard: &Leaderboard, request: LeaderboardDataRequest, start: usize, end: usize, max_details_len: usize) -> Result<Vec<LeaderboardEntry>, SteamError>;
+        fn request_global_achievement_percentages_async => request_global_achievement_percentages() -> Result<GameId, SteamError>;
+    }
 }
 
 #[derive(Clone, Debug)]
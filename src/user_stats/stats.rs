@@ -199,33 +199,24 @@ impl<M> AchievementHelper<'_, M> {
     }
 
     fn internal_get_achievement_icon(&self, avoid_big_icons: bool) -> Option<(Vec<u8>, u32, u32)> {
+        let img = self.get_achievement_icon_handle()?;
+        let (width, height) = img.size()?;
+        if avoid_big_icons && (width != 64 || height != 64) {
+            return None;
+        }
+        Some((img.rgba()?, width, height))
+    }
+
+    /// Gets a handle to the icon for an achievement.
+    ///
+    /// This may return None if Steam has not retrieved the icon yet. In that case an
+    /// `UserAchievementIconFetched` callback will be processed
+    pub fn get_achievement_icon_handle(&self) -> Option<ImageHandle> {
         unsafe {
-            let utils: *mut sys::ISteamUtils = sys::SteamAPI_SteamUtils_v010();
-            let img = sys::SteamAPI_ISteamUserStats_GetAchievementIcon(
+            ImageHandle::from_raw(sys::SteamAPI_ISteamUserStats_GetAchievementIcon(
                 self.parent.user_stats,
                 self.name.as_ptr() as *const _,
-            );
-            if img == 0 {
-                return None;
-            }
-            let mut width = 0;
-            let mut height = 0;
-            if !sys::SteamAPI_ISteamUtils_GetImageSize(utils, img, &mut width, &mut height) {
-                return None;
-            }
-            if avoid_big_icons && (width != 64 || height != 64) {
-                return None;
-            }
-            let mut dest = vec![0; (width * height * 4).try_into().unwrap()];
-            if !sys::SteamAPI_ISteamUtils_GetImageRGBA(
-                utils,
-                img,
-                dest.as_mut_ptr(),
-                (width * height * 4).try_into().unwrap(),
-            ) {
-                return None;
-            }
-            Some((dest, width, height))
+            ))
         }
     }
 
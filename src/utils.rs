@@ -12,8 +12,15 @@ pub struct Utils<Manager> {
     pub(crate) _inner: Arc<Inner<Manager>>,
 }
 
+/// Called when the Big Picture gamepad text input opened with
+/// `Utils::show_gamepad_text_input` has been closed.
+///
+/// The entered text can be read with
+/// `Utils::get_entered_gamepad_text_input`.
 #[derive(Clone, Debug)]
 pub struct GamepadTextInputDismissed {
+    /// The length of the entered text in bytes, if the user submitted
+    /// it
     pub submitted_text_len: Option<u32>,
 }
 
@@ -29,6 +36,8 @@ unsafe impl Callback for GamepadTextInputDismissed {
     }
 }
 
+/// Called when the floating keyboard opened with
+/// `Utils::show_floating_gamepad_text_input` has been closed.
 #[derive(Clone, Debug)]
 pub struct FloatingGamepadTextInputDismissed;
 
@@ -41,6 +50,126 @@ unsafe impl Callback for FloatingGamepadTextInputDismissed {
     }
 }
 
+/// Called when the country of the user changed. The new country can
+/// be read with `Utils::ip_country`.
+#[derive(Clone, Debug)]
+pub struct IPCountryChanged;
+
+unsafe impl Callback for IPCountryChanged {
+    const ID: i32 = 701;
+    const SIZE: i32 = ::std::mem::size_of::<sys::IPCountry_t>() as i32;
+
+    unsafe fn from_raw(_: *mut c_void) -> Self {
+        IPCountryChanged
+    }
+}
+
+/// Called when running on a laptop and there is less than 10 minutes
+/// of battery left. Posted again every minute until the battery runs
+/// out or the device is plugged in.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LowBatteryPower {
+    /// The estimated number of minutes of battery left
+    pub minutes_battery_left: u8,
+}
+
+unsafe impl Callback for LowBatteryPower {
+    const ID: i32 = 702;
+    const SIZE: i32 = ::std::mem::size_of::<sys::LowBatteryPower_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::LowBatteryPower_t);
+        LowBatteryPower {
+            minutes_battery_left: val.m_nMinutesBatteryLeft,
+        }
+    }
+}
+
+/// Called when Steam wants to shut down, e.g. because the user is
+/// exiting Steam.
+///
+/// The game should save any progress and exit shortly after receiving
+/// this.
+#[derive(Clone, Debug)]
+pub struct SteamShutdown;
+
+unsafe impl Callback for SteamShutdown {
+    const ID: i32 = 704;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamShutdown_t>() as i32;
+
+    unsafe fn from_raw(_: *mut c_void) -> Self {
+        SteamShutdown
+    }
+}
+
+/// Called when the game is resuming after the device was suspended,
+/// e.g. on the Steam Deck.
+#[derive(Clone, Debug)]
+pub struct AppResumingFromSuspend;
+
+unsafe impl Callback for AppResumingFromSuspend {
+    const ID: i32 = 736;
+    const SIZE: i32 = ::std::mem::size_of::<sys::AppResumingFromSuspend_t>() as i32;
+
+    unsafe fn from_raw(_: *mut c_void) -> Self {
+        AppResumingFromSuspend
+    }
+}
+
+/// A handle to an image loaded by Steam, such as an avatar or an
+/// achievement icon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImageHandle(i32);
+
+impl ImageHandle {
+    /// Creates an `ImageHandle` from a raw image handle returned by the
+    /// steam api.
+    ///
+    /// Returns `None` for the invalid (0) and still loading (-1)
+    /// handles.
+    pub fn from_raw(handle: i32) -> Option<ImageHandle> {
+        (handle > 0).then_some(ImageHandle(handle))
+    }
+
+    /// Returns the raw image handle
+    pub fn raw(&self) -> i32 {
+        self.0
+    }
+
+    /// Returns the width and height of the image in pixels
+    pub fn size(&self) -> Option<(u32, u32)> {
+        unsafe {
+            let utils = sys::SteamAPI_SteamUtils_v010();
+            let mut width = 0;
+            let mut height = 0;
+            sys::SteamAPI_ISteamUtils_GetImageSize(utils, self.0, &mut width, &mut height)
+                .then_some((width, height))
+        }
+    }
+
+    /// Returns the pixels of the image in RGBA format, 4 bytes per
+    /// pixel and row by row
+    pub fn rgba(&self) -> Option<Vec<u8>> {
+        let (width, height) = self.size()?;
+        unsafe {
+            let utils = sys::SteamAPI_SteamUtils_v010();
+            let len = width as usize * height as usize * 4;
+            let mut dest = vec![0; len];
+            sys::SteamAPI_ISteamUtils_GetImageRGBA(utils, self.0, dest.as_mut_ptr(), len as i32)
+                .then_some(dest)
+        }
+    }
+
+    /// Returns the image as an `ImageBuffer` from the image crate
+    #[cfg(feature = "image")]
+    pub fn to_image(&self) -> Option<image::ImageBuffer<image::Rgba<u8>, Vec<u8>>> {
+        let (width, height) = self.size()?;
+        image::ImageBuffer::from_vec(width, height, self.rgba()?)
+    }
+}
+
+/// The corner of the screen overlay notifications are shown in
 pub enum NotificationPosition {
     TopLeft,
     TopRight,
@@ -84,10 +213,15 @@ impl From<GamepadTextInputLineMode> for sys::EGamepadTextInputLineMode {
     }
 }
 
+/// The kind of floating keyboard to show
 pub enum FloatingGamepadTextInputMode {
+    /// Enter dismisses the keyboard
     SingleLine,
+    /// User needs to explicitly dismiss the keyboard
     MultipleLines,
+    /// Keyboard layout is optimized for email entry
     Email,
+    /// Keyboard layout is optimized for number entry
     Numeric,
 }
 
@@ -110,6 +244,33 @@ impl From<FloatingGamepadTextInputMode> for sys::EFloatingGamepadTextInputMode {
     }
 }
 
+/// The context of text passed to `Utils::filter_text`
+pub enum TextFilteringContext {
+    /// Unknown context
+    Unknown,
+    /// Game content, only legally required filtering is performed
+    GameContent,
+    /// Chat from another player
+    Chat,
+    /// A character or item name
+    Name,
+}
+
+impl From<TextFilteringContext> for sys::ETextFilteringContext {
+    fn from(context: TextFilteringContext) -> Self {
+        match context {
+            TextFilteringContext::Unknown => {
+                sys::ETextFilteringContext::k_ETextFilteringContextUnknown
+            }
+            TextFilteringContext::GameContent => {
+                sys::ETextFilteringContext::k_ETextFilteringContextGameContent
+            }
+            TextFilteringContext::Chat => sys::ETextFilteringContext::k_ETextFilteringContextChat,
+            TextFilteringContext::Name => sys::ETextFilteringContext::k_ETextFilteringContextName,
+        }
+    }
+}
+
 lazy_static! {
     /// Global rust warning callback
     static ref WARNING_CALLBACK: RwLock<Option<Box<dyn Fn(i32, &CStr) + Send + Sync>>> = RwLock::new(None);
@@ -147,6 +308,9 @@ impl<Manager> Utils<Manager> {
     }
 
     /// Returns the country code of the current user based on their IP
+    ///
+    /// This is a two letter ISO 3166-1-alpha-2 code, e.g. "US" or "GB".
+    /// The `IPCountryChanged` callback is posted if it changes.
     pub fn ip_country(&self) -> String {
         unsafe {
             let ipcountry = sys::SteamAPI_ISteamUtils_GetIPCountry(self.utils);
@@ -155,6 +319,17 @@ impl<Manager> Utils<Manager> {
         }
     }
 
+    /// Returns the current battery power as a percentage between 0 and
+    /// 100, or `None` if the device is running on AC power.
+    pub fn current_battery_power(&self) -> Option<u8> {
+        unsafe {
+            match sys::SteamAPI_ISteamUtils_GetCurrentBatteryPower(self.utils) {
+                255 => None,
+                power => Some(power),
+            }
+        }
+    }
+
     /// Returns whether or not the overlay is enabled in Steam
     pub fn is_overlay_enabled(&self) -> bool {
         unsafe { sys::SteamAPI_ISteamUtils_IsOverlayEnabled(self.utils) }
@@ -174,6 +349,8 @@ impl<Manager> Utils<Manager> {
 
     /// Returns the current real time on the Steam servers
     /// in Unix epoch format (seconds since 1970/1/1 UTC).
+    ///
+    /// Unlike the local clock this can't be changed by the user.
     pub fn get_server_real_time(&self) -> u32 {
         unsafe { sys::SteamAPI_ISteamUtils_GetServerRealTime(self.utils) }
     }
@@ -196,6 +373,16 @@ impl<Manager> Utils<Manager> {
         }
     }
 
+    /// Sets the inset of the overlay notification from the corner
+    /// specified by `set_overlay_notification_position`, in pixels.
+    ///
+    /// A value of (0, 0) resets the position to the corner.
+    pub fn set_overlay_notification_inset(&self, horizontal: i32, vertical: i32) {
+        unsafe {
+            sys::SteamAPI_ISteamUtils_SetOverlayNotificationInset(self.utils, horizontal, vertical);
+        }
+    }
+
     /// Sets the Steam warning callback, which is called to emit warning messages.
     ///
     /// The passed-in function takes two arguments: a severity level (0 = info, 1 = warning) and
@@ -215,6 +402,65 @@ impl<Manager> Utils<Manager> {
         }
     }
 
+    /// Sets the Steam warning callback to forward messages to the `log` crate.
+    ///
+    /// Informational messages are logged at the `info` level and warnings at the
+    /// `warn` level, both with the `steamworks` target. Note that Steam only emits
+    /// these messages if the game was launched with the `-debug_steamapi` argument.
+    ///
+    /// `tracing` users can receive these through the `tracing-log` bridge.
+    #[cfg(feature = "log")]
+    pub fn set_warning_callback_to_log(&self) {
+        self.set_warning_callback(|level, msg| {
+            let msg = msg.to_string_lossy();
+            let msg = msg.trim_end();
+            if level == 0 {
+                log::info!(target: "steamworks", "{}", msg);
+            } else {
+                log::warn!(target: "steamworks", "{}", msg);
+            }
+        });
+    }
+
+    /// Initializes text filtering, loading the dictionaries for the
+    /// language the game is running in.
+    ///
+    /// Must be called before `filter_text`. Returns false if filtering
+    /// is not available.
+    pub fn init_filter_text(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamUtils_InitFilterText(self.utils, 0) }
+    }
+
+    /// Filters the given text based on the user's Steam profanity
+    /// settings and any legally required filtering.
+    ///
+    /// `source` is the steam id of the user who wrote the text, which is
+    /// used to avoid filtering the user's own text. Filtered characters
+    /// are replaced with `*`.
+    pub fn filter_text(
+        &self,
+        context: TextFilteringContext,
+        source: SteamId,
+        text: &str,
+    ) -> String {
+        unsafe {
+            let input = CString::new(text).unwrap();
+            // The filtered text is never longer than the input
+            let mut buffer = vec![0 as c_char; input.as_bytes_with_nul().len()];
+            sys::SteamAPI_ISteamUtils_FilterText(
+                self.utils,
+                context.into(),
+                source.0,
+                input.as_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len() as u32,
+            );
+            CStr::from_ptr(buffer.as_ptr())
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
     /// Gets the gamepad text input from the Big Picture overlay.
     ///
     /// This must be called within the `show_gamepad_text_input` callback. Returns `Some(String)` if user submitted the
@@ -225,23 +471,49 @@ impl<Manager> Utils<Manager> {
     ) -> Option<String> {
         unsafe {
             let len = dismissed_data.submitted_text_len?;
-            let mut buf = vec![0u8; len as usize];
+            // Leave room for the null terminator that Steam writes
+            let mut buf = vec![0u8; len as usize + 1];
 
             sys::SteamAPI_ISteamUtils_GetEnteredGamepadTextInput(
                 self.utils,
                 buf.as_mut_ptr() as *mut i8,
-                len,
+                buf.len() as u32,
             )
-            .then(|| String::from_utf8(buf).expect("Steamworks returned invalid UTF-8 string"))
+            .then(|| {
+                let end = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+                buf.truncate(end);
+                String::from_utf8(buf).expect("Steamworks returned invalid UTF-8 string")
+            })
         }
     }
 
+    /// Closes the Big Picture text input dialog opened with
+    /// `show_gamepad_text_input`.
+    pub fn dismiss_gamepad_text_input(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamUtils_DismissGamepadTextInput(self.utils) }
+    }
+
     /// Checks if Steam is running on a Steam Deck device.
     pub fn is_steam_running_on_steam_deck(&self) -> bool {
         unsafe { sys::SteamAPI_ISteamUtils_IsSteamRunningOnSteamDeck(self.utils) }
     }
 
+    /// Checks if Steam and the Steam overlay are running in Big Picture
+    /// mode.
+    pub fn is_steam_in_big_picture_mode(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamUtils_IsSteamInBigPictureMode(self.utils) }
+    }
+
+    /// Checks if Steam is running in VR mode.
+    pub fn is_steam_running_in_vr(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamUtils_IsSteamRunningInVR(self.utils) }
+    }
+
     /// Activates the Big Picture text input dialog which only supports gamepad input.
+    ///
+    /// `dismissed_cb` is called with a `GamepadTextInputDismissed` once the
+    /// dialog is closed. Returns false if the dialog could not be shown,
+    /// e.g. because the overlay is disabled.
     pub fn show_gamepad_text_input<F>(
         &self,
         input_mode: GamepadTextInputMode,
@@ -257,7 +529,7 @@ impl<Manager> Utils<Manager> {
         unsafe {
             let description = CString::new(description).unwrap();
             let existing_text = existing_text.map(|s| CString::new(s).unwrap());
-            register_callback(&self._inner, dismissed_cb);
+            register_callback(&self._inner, dismissed_cb).forget();
             sys::SteamAPI_ISteamUtils_ShowGamepadTextInput(
                 self.utils,
                 input_mode.into(),
@@ -293,7 +565,8 @@ impl<Manager> Utils<Manager> {
         unsafe {
             register_callback(&self._inner, move |_: FloatingGamepadTextInputDismissed| {
                 dismissed_cb();
-            });
+            })
+            .forget();
             sys::SteamAPI_ISteamUtils_ShowFloatingGamepadTextInput(
                 self.utils,
                 keyboard_mode.into(),
@@ -304,6 +577,12 @@ impl<Manager> Utils<Manager> {
             )
         }
     }
+
+    /// Closes the floating keyboard opened with
+    /// `show_floating_gamepad_text_input`.
+    pub fn dismiss_floating_gamepad_text_input(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamUtils_DismissFloatingGamepadTextInput(self.utils) }
+    }
 }
 
 pub(crate) struct SteamParamStringArray(Vec<*mut i8>);
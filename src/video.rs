@@ -0,0 +1,60 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 4600;
+
+/// Access to the steam video interface
+pub struct Video<Manager> {
+    pub(crate) video: *mut sys::ISteamVideo,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+impl<Manager> Video<Manager> {
+    /// Requests the URL of the video stream of the given video app.
+    ///
+    /// The URL is delivered through the `GetVideoURLResult` callback.
+    pub fn get_video_url(&self, video_app_id: AppId) {
+        unsafe { sys::SteamAPI_ISteamVideo_GetVideoURL(self.video, video_app_id.0) }
+    }
+
+    /// Returns the number of viewers if the user is currently
+    /// broadcasting, otherwise `None`
+    pub fn broadcasting_viewers(&self) -> Option<u32> {
+        unsafe {
+            let mut viewers = 0;
+            if sys::SteamAPI_ISteamVideo_IsBroadcasting(self.video, &mut viewers) {
+                Some(viewers.max(0) as u32)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Called in response to `Video::get_video_url`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GetVideoURLResult {
+    pub video_app_id: AppId,
+    /// The URL of the video stream. Empty if the request failed.
+    pub url: String,
+    pub error: Option<SteamError>,
+}
+
+unsafe impl Callback for GetVideoURLResult {
+    const ID: i32 = CALLBACK_BASE_ID + 11;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GetVideoURLResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GetVideoURLResult_t);
+        GetVideoURLResult {
+            video_app_id: AppId(val.m_unVideoAppID),
+            url: CStr::from_ptr(val.m_rgchURL.as_ptr())
+                .to_string_lossy()
+                .into_owned(),
+            error: match val.m_eResult {
+                sys::EResult::k_EResultOK => None,
+                error => Some(error.into()),
+            },
+        }
+    }
+}
@@ -88,6 +88,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .default_enum_style(bindgen::EnumVariation::Rust {
                 non_exhaustive: true,
             })
+            .bitfield_enum("ISteamHTMLSurface_EHTMLKeyModifiers")
             .generate()
             .expect("Unable to generate bindings");
 
@@ -16768,15 +16768,51 @@ pub enum ISteamHTMLSurface_EMouseCursor {
     dc_custom = 40,
     dc_last = 41,
 }
-#[repr(u32)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum ISteamHTMLSurface_EHTMLKeyModifiers {
-    k_eHTMLKeyModifier_None = 0,
-    k_eHTMLKeyModifier_AltDown = 1,
-    k_eHTMLKeyModifier_CtrlDown = 2,
-    k_eHTMLKeyModifier_ShiftDown = 4,
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_None: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(0);
+}
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_AltDown: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(1);
+}
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_CtrlDown: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(2);
 }
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_ShiftDown: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(4);
+}
+impl ::std::ops::BitOr<ISteamHTMLSurface_EHTMLKeyModifiers> for ISteamHTMLSurface_EHTMLKeyModifiers {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        ISteamHTMLSurface_EHTMLKeyModifiers(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for ISteamHTMLSurface_EHTMLKeyModifiers {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: ISteamHTMLSurface_EHTMLKeyModifiers) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<ISteamHTMLSurface_EHTMLKeyModifiers> for ISteamHTMLSurface_EHTMLKeyModifiers {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        ISteamHTMLSurface_EHTMLKeyModifiers(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for ISteamHTMLSurface_EHTMLKeyModifiers {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: ISteamHTMLSurface_EHTMLKeyModifiers) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ISteamHTMLSurface_EHTMLKeyModifiers(pub ::std::os::raw::c_uint);
 #[test]
 fn bindgen_test_layout_ISteamHTMLSurface() {
     assert_eq!(